@@ -0,0 +1,192 @@
+//! Build image URLs of cards, set logos and symbols, and serie logos.
+//!
+//! TCGdex API gives image base URLs, to complete with a quality and a file extension.
+//! An object without image gives None instead of an empty URL.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use tcgdex_api::{Card, Query, Tcgdex};
+//! use tcgdex_api::assets::{Extension, Quality};
+//! let tcgdex = Tcgdex::new();
+//! let card: Card = tcgdex.cards().fetch(Some(&Query::new().with_id("swsh3-136"))).unwrap();
+//! match card.image_asset() {
+//!     Some(image) => println!("image = {}", image.url(Quality::High, Extension::Webp)),
+//!     None => println!("no image"),
+//! }
+//! ```
+
+use crate::{Card, CardBrief, Serie, SerieBrief, Set, SetBrief};
+use std::fmt::{Display, Formatter};
+
+/// Quality of a card image.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Quality {
+    /// Small image, about 245 x 337 pixels.
+    Low,
+
+    /// Large image, about 600 x 825 pixels.
+    High,
+}
+
+impl Quality {
+    /// Get the quality name used in image URLs.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::High => "high",
+        }
+    }
+}
+
+impl Display for Quality {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// File format of an image.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Extension {
+    /// PNG, with transparency.
+    Png,
+
+    /// JPEG, without transparency.
+    Jpg,
+
+    /// WebP, the smallest files.
+    Webp,
+}
+
+impl Extension {
+    /// Get the file extension, without dot.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpg => "jpg",
+            Self::Webp => "webp",
+        }
+    }
+}
+
+impl Display for Extension {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Image of a card, available in several qualities and formats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CardImage<'a>(&'a str);
+
+impl<'a> CardImage<'a> {
+    /// Get the image of a card with `base_url` image, or None if the card has no image.
+    #[must_use]
+    pub fn new(base_url: &'a str) -> Option<Self> {
+        non_empty(base_url).map(Self)
+    }
+
+    /// Get the base URL, as returned by TCGdex API.
+    #[must_use]
+    pub const fn base_url(&self) -> &'a str {
+        self.0
+    }
+
+    /// Get the URL of the image in `quality` and `extension` format.
+    #[must_use]
+    pub fn url(&self, quality: Quality, extension: Extension) -> String {
+        format!("{}/{quality}.{extension}", self.0)
+    }
+}
+
+/// Logo or symbol image, available in several formats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Image<'a>(&'a str);
+
+impl<'a> Image<'a> {
+    /// Get the image with `base_url`, or None if there is no image.
+    #[must_use]
+    pub fn new(base_url: &'a str) -> Option<Self> {
+        non_empty(base_url).map(Self)
+    }
+
+    /// Get the base URL, as returned by TCGdex API.
+    #[must_use]
+    pub const fn base_url(&self) -> &'a str {
+        self.0
+    }
+
+    /// Get the URL of the image in `extension` format.
+    #[must_use]
+    pub fn url(&self, extension: Extension) -> String {
+        format!("{}.{extension}", self.0)
+    }
+}
+
+/// Get `url` without surrounding spaces and trailing `/`, or None if it is empty.
+fn non_empty(url: &str) -> Option<&str> {
+    let url = url.trim().trim_end_matches('/');
+    (!url.is_empty()).then_some(url)
+}
+
+impl Card {
+    /// Get the card image, or None if the card has no image.
+    #[must_use]
+    pub fn image_asset(&self) -> Option<CardImage<'_>> {
+        CardImage::new(&self.image)
+    }
+}
+
+impl CardBrief {
+    /// Get the card image, or None if the card has no image.
+    #[must_use]
+    pub fn image_asset(&self) -> Option<CardImage<'_>> {
+        CardImage::new(&self.image)
+    }
+}
+
+impl Set {
+    /// Get the set logo, or None if the set has no logo.
+    #[must_use]
+    pub fn logo_asset(&self) -> Option<Image<'_>> {
+        Image::new(&self.logo)
+    }
+
+    /// Get the set symbol, or None if the set has no symbol.
+    #[must_use]
+    pub fn symbol_asset(&self) -> Option<Image<'_>> {
+        Image::new(&self.symbol)
+    }
+}
+
+impl SetBrief {
+    /// Get the set logo, or None if the set has no logo.
+    #[must_use]
+    pub fn logo_asset(&self) -> Option<Image<'_>> {
+        Image::new(&self.logo)
+    }
+
+    /// Get the set symbol, or None if the set has no symbol.
+    #[must_use]
+    pub fn symbol_asset(&self) -> Option<Image<'_>> {
+        Image::new(&self.symbol)
+    }
+}
+
+impl Serie {
+    /// Get the serie logo, or None if the serie has no logo.
+    #[must_use]
+    pub fn logo_asset(&self) -> Option<Image<'_>> {
+        Image::new(&self.logo)
+    }
+}
+
+impl SerieBrief {
+    /// Get the serie logo, or None if the serie has no logo.
+    #[must_use]
+    pub fn logo_asset(&self) -> Option<Image<'_>> {
+        Image::new(&self.logo)
+    }
+}
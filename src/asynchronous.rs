@@ -0,0 +1,335 @@
+//! Non-blocking counterpart of [`Tcgdex`](crate::Tcgdex), available with `async` feature.
+//!
+//! [`AsyncTcgdex`] gives access to the same module interfaces as [`Tcgdex`](crate::Tcgdex),
+//! with the same models and the same [`Query`] builder, but each `fetch` is an `async fn`.
+
+use crate::endpoints::cards::{CardApi, CardBrief, CardsFor};
+use crate::endpoints::categories::CategoryApi;
+use crate::endpoints::dex_ids::DexIdApi;
+use crate::endpoints::energy_types::EnergyTypeApi;
+use crate::endpoints::hps::HpApi;
+use crate::endpoints::illustrators::IllustratorApi;
+use crate::endpoints::rarities::RarityApi;
+use crate::endpoints::regulation_marks::RegulationMarkApi;
+use crate::endpoints::retreats::RetreatApi;
+use crate::endpoints::series::SerieApi;
+use crate::endpoints::sets::SetApi;
+use crate::endpoints::stages::StageApi;
+use crate::endpoints::suffixes::SuffixApi;
+use crate::endpoints::trainer_types::TrainerTypeApi;
+use crate::endpoints::types::TypeApi;
+use crate::endpoints::variants::VariantApi;
+use crate::errors::{self, ApiError};
+use crate::is_empty::IsEmpty;
+use crate::lenient::{self, Extra, Lenient};
+use crate::pipeline::{Pipeline, Start};
+use crate::query::{endpoint_url, Query, URL_BASE};
+use crate::{Lang, TcgdexBuilder};
+use futures_util::{stream, StreamExt};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+/// Generic structure containing a unique asynchronous REST client and selected langage.
+///
+/// Create only one instance and use it for all requests.
+///
+/// Langage can be changed. English is default value.
+///
+/// Use [`TcgdexBuilder::build_async`] to change API base URL or HTTP client settings.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use tcgdex_api::AsyncTcgdex;
+/// # #[tokio::main]
+/// # async fn main() {
+/// let tcgdex = AsyncTcgdex::new();
+/// println!("types = {:?}", tcgdex.types().fetch().await.unwrap());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncTcgdex {
+    pub(crate) client: reqwest::Client,
+    pub(crate) lang: Lang,
+    pub(crate) base_url: String,
+    pub(crate) pipeline: Pipeline,
+}
+
+impl Default for AsyncTcgdex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsyncTcgdex {
+    /// Create new `AsyncTcgdex` with asynchronous client and english langage.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            lang: Lang::EN,
+            base_url: URL_BASE.to_string(),
+            pipeline: Pipeline::default(),
+        }
+    }
+
+    /// Get a [`TcgdexBuilder`] to configure a new instance.
+    #[must_use]
+    pub fn builder() -> TcgdexBuilder {
+        TcgdexBuilder::new()
+    }
+
+    /// Set cards language.
+    pub fn set_lang(&mut self, lang: Lang) {
+        self.lang = lang;
+    }
+
+    /// Get an interface to types module.
+    #[must_use]
+    pub fn types(&self) -> TypeApi<'_, Self> {
+        TypeApi(self)
+    }
+
+    /// Get an interface to categories module.
+    #[must_use]
+    pub fn categories(&self) -> CategoryApi<'_, Self> {
+        CategoryApi(self)
+    }
+
+    /// Get an interface to hps module.
+    #[must_use]
+    pub fn hps(&self) -> HpApi<'_, Self> {
+        HpApi(self)
+    }
+
+    /// Get an interface to illustrators module.
+    #[must_use]
+    pub fn illustrators(&self) -> IllustratorApi<'_, Self> {
+        IllustratorApi(self)
+    }
+
+    /// Get an interface to rarities module.
+    #[must_use]
+    pub fn rarities(&self) -> RarityApi<'_, Self> {
+        RarityApi(self)
+    }
+
+    /// Get an interface to retreats module.
+    #[must_use]
+    pub fn retreats(&self) -> RetreatApi<'_, Self> {
+        RetreatApi(self)
+    }
+
+    /// Get an interface to stages module.
+    #[must_use]
+    pub fn stages(&self) -> StageApi<'_, Self> {
+        StageApi(self)
+    }
+
+    /// Get an interface to suffixes module.
+    #[must_use]
+    pub fn suffixes(&self) -> SuffixApi<'_, Self> {
+        SuffixApi(self)
+    }
+
+    /// Get an interface to trainer types module.
+    #[must_use]
+    pub fn trainer_types(&self) -> TrainerTypeApi<'_, Self> {
+        TrainerTypeApi(self)
+    }
+
+    /// Get an interface to energy types module.
+    #[must_use]
+    pub fn energy_types(&self) -> EnergyTypeApi<'_, Self> {
+        EnergyTypeApi(self)
+    }
+
+    /// Get an interface to regulation marks module.
+    #[must_use]
+    pub fn regulation_marks(&self) -> RegulationMarkApi<'_, Self> {
+        RegulationMarkApi(self)
+    }
+
+    /// Get an interface to variants module.
+    #[must_use]
+    pub fn variants(&self) -> VariantApi<'_, Self> {
+        VariantApi(self)
+    }
+
+    /// Get an interface to dex ids module.
+    #[must_use]
+    pub fn dex_ids(&self) -> DexIdApi<'_, Self> {
+        DexIdApi(self)
+    }
+
+    /// Get an interface to series module.
+    #[must_use]
+    pub fn series(&self) -> SerieApi<'_, Self> {
+        SerieApi(self)
+    }
+
+    /// Get an interface to sets module.
+    #[must_use]
+    pub fn sets(&self) -> SetApi<'_, Self> {
+        SetApi(self)
+    }
+
+    /// Get an interface to cards module.
+    #[must_use]
+    pub fn cards(&self) -> CardApi<'_, Self> {
+        CardApi(self)
+    }
+
+    /// Build the URL to reach `object_name` endpoint with current langage.
+    pub(crate) fn url(&self, object_name: &str, query: Option<&Query>) -> String {
+        endpoint_url(&self.base_url, self.lang, object_name, query)
+    }
+
+    /// Send a GET request to `url` and decode the JSON body.
+    ///
+    /// Every endpoint goes through this function. Responses are taken from the snapshot or the cache, if any.
+    /// Requests sent are rate limited and retried, if configured.
+    pub(crate) async fn get<T>(&self, url: &str) -> errors::Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self
+            .respond(url)
+            .await
+            .map_err(|error| ApiError::request(url, error))?;
+        errors::decode(url, &response)
+    }
+
+    /// Get the response to a GET request to `url`, from the snapshot, the cache or the network.
+    async fn respond(&self, url: &str) -> reqwest::Result<http::Response<String>> {
+        let pending = match self.pipeline.start(&self.base_url, self.lang, url) {
+            Start::Done(response) => return Ok(response),
+            Start::Send(pending) => pending,
+        };
+        let request = self.client.get(url).headers(pending.headers.clone());
+        let response = self.send(request).await?;
+        Ok(self.pipeline.finish(pending, response))
+    }
+
+    /// Send `request` after waiting for the rate limiter, and send it again while the retry policy allows.
+    ///
+    /// The body is read in each attempt, so a connection reset while reading it is retried too.
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> reqwest::Result<http::Response<Vec<u8>>> {
+        let mut attempt = 1;
+        loop {
+            // a request with a streamed body cannot be cloned, so it is sent only once.
+            let Some(next) = request.try_clone() else {
+                return self.send_once(request).await;
+            };
+            let result = self.send_once(next).await;
+            let Some(delay) = self.pipeline.retry_delay(attempt, &result) else {
+                return result;
+            };
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Send `request` after waiting for the rate limiter, and read its body.
+    async fn send_once(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> reqwest::Result<http::Response<Vec<u8>>> {
+        self.throttle().await;
+        let response = request.send().await?;
+        let (status, headers) = (response.status(), response.headers().clone());
+        Ok(crate::http_response(
+            status,
+            headers,
+            response.bytes().await?.to_vec(),
+        ))
+    }
+
+    /// Wait for the rate limiter, if any.
+    async fn throttle(&self) {
+        if let Some(delay) = self.pipeline.throttle_delay() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Fetch object `id` of `object_name` endpoint in each langage of `langs`,
+    /// at most [`MAX_CONCURRENCY`](crate::MAX_CONCURRENCY) at once.
+    ///
+    /// Langages in which the object does not exist are not in the returned map.
+    pub(crate) async fn fetch_langs<T>(
+        &self,
+        object_name: &str,
+        id: &str,
+        langs: &[Lang],
+    ) -> errors::Result<HashMap<Lang, T>>
+    where
+        T: DeserializeOwned + IsEmpty,
+    {
+        let query = Query::new().with_id(id);
+        let requests = langs.iter().map(|&lang| {
+            let url = endpoint_url(&self.base_url, lang, object_name, Some(&query));
+            async move {
+                let result = self.get::<T>(&url).await.and_then(errors::non_empty);
+                (lang, result)
+            }
+        });
+        let results = stream::iter(requests)
+            .buffered(crate::MAX_CONCURRENCY)
+            .collect()
+            .await;
+        errors::collect_langs(results)
+    }
+
+    /// Fetch object `id` of `object_name` endpoint, decoding it leniently.
+    pub(crate) async fn fetch_lenient<T>(
+        &self,
+        object_name: &str,
+        id: &str,
+    ) -> errors::Result<Lenient<T>>
+    where
+        T: DeserializeOwned + IsEmpty + Extra,
+    {
+        let url = self.url(object_name, Some(&Query::new().with_id(id)));
+        let lenient: Lenient<T> = lenient::decode(&url, self.get(&url).await?)?;
+        if lenient.value.is_empty() {
+            return Err(ApiError::EmptyResponse);
+        }
+        Ok(lenient)
+    }
+
+    /// Fetch the cards with `value` of `object_name` list endpoint, like `types/Fire`.
+    pub(crate) async fn cards_for(
+        &self,
+        object_name: &str,
+        value: &str,
+    ) -> errors::Result<Vec<CardBrief>> {
+        let url = self.url(object_name, Some(&Query::new().with_id(value)));
+        let cards: CardsFor = self.get(&url).await?;
+        errors::non_empty(cards.cards)
+    }
+
+    /// Fetch objects `ids` of `object_name` endpoint, at most [`MAX_CONCURRENCY`](crate::MAX_CONCURRENCY) at once.
+    ///
+    /// Results are in order of first occurrence of their id, duplicates being fetched once.
+    pub(crate) async fn fetch_many<T>(
+        &self,
+        object_name: &str,
+        ids: &[impl AsRef<str>],
+    ) -> Vec<(String, errors::Result<T>)>
+    where
+        T: DeserializeOwned + IsEmpty,
+    {
+        let requests = crate::unique_ids(ids).into_iter().map(|id| async move {
+            let url = self.url(object_name, Some(&Query::new().with_id(&id)));
+            let result = self.get::<T>(&url).await.and_then(errors::non_empty);
+            (id, result)
+        });
+        stream::iter(requests)
+            .buffered(crate::MAX_CONCURRENCY)
+            .collect()
+            .await
+    }
+}
@@ -0,0 +1,246 @@
+//! To configure a [`Tcgdex`] instance using [`TcgdexBuilder`].
+
+use crate::cache::{Cache, CacheLayer};
+use crate::errors;
+use crate::pipeline::Pipeline;
+use crate::query::URL_BASE;
+use crate::retry::{RateLimiter, RetryPolicy};
+use crate::snapshot::Snapshot;
+use crate::{Lang, Tcgdex};
+use reqwest::header::HeaderMap;
+use reqwest::Proxy;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Total timeout of a request sent by a client built with a [`TcgdexBuilder`], blocking or asynchronous,
+/// unless set with [`TcgdexBuilder::with_timeout`].
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Apply the HTTP settings of a [`TcgdexBuilder`] to a blocking or asynchronous `reqwest` client builder.
+macro_rules! configure_client {
+    ($builder:expr, $settings:expr) => {{
+        let mut builder = $builder
+            .default_headers($settings.default_headers.clone())
+            .timeout($settings.timeout);
+        if let Some(timeout) = $settings.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(user_agent) = &$settings.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        for proxy in &$settings.proxies {
+            builder = builder.proxy(proxy.clone());
+        }
+        builder
+    }};
+}
+
+/// To build a [`Tcgdex`] (or an `AsyncTcgdex` with `async` feature) with specified settings.
+///
+/// Settings not set keep their default value: official TCGdex API, english langage, [`DEFAULT_TIMEOUT`]
+/// and default `reqwest` client.
+///
+/// If a client is injected with [`TcgdexBuilder::with_client`], HTTP settings
+/// (timeouts, user agent, default headers and proxies) have no effect on it.
+///
+/// # Example
+///
+/// ```rust
+/// # use tcgdex_api::{Lang, TcgdexBuilder};
+/// # use std::time::Duration;
+/// let tcgdex = TcgdexBuilder::new()
+///     .with_base_url("https://tcgdex.example.com/v2")
+///     .with_lang(Lang::FR)
+///     .with_timeout(Duration::from_secs(10))
+///     .with_user_agent("my-deck-builder/1.0")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct TcgdexBuilder {
+    base_url: String,
+    lang: Lang,
+    timeout: Duration,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    default_headers: HeaderMap,
+    proxies: Vec<Proxy>,
+    client: Option<reqwest::blocking::Client>,
+    #[cfg(feature = "async")]
+    async_client: Option<reqwest::Client>,
+    pipeline: Pipeline,
+}
+
+impl Default for TcgdexBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TcgdexBuilder {
+    /// Create a builder with default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            base_url: URL_BASE.to_string(),
+            lang: Lang::EN,
+            timeout: DEFAULT_TIMEOUT,
+            connect_timeout: None,
+            user_agent: None,
+            default_headers: HeaderMap::new(),
+            proxies: Vec::new(),
+            client: None,
+            #[cfg(feature = "async")]
+            async_client: None,
+            pipeline: Pipeline::default(),
+        }
+    }
+
+    /// Set the base URL of the API, language code excluded (for example `https://api.tcgdex.net/v2/`).
+    ///
+    /// A trailing `/` is added if missing.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_string();
+        if !self.base_url.ends_with('/') {
+            self.base_url.push('/');
+        }
+        self
+    }
+
+    /// Set cards language.
+    #[must_use]
+    pub const fn with_lang(mut self, lang: Lang) -> Self {
+        self.lang = lang;
+        self
+    }
+
+    /// Set the total timeout of a request. Default is [`DEFAULT_TIMEOUT`].
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Get the total timeout of a request.
+    #[must_use]
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Set the timeout of the connect phase of a request.
+    #[must_use]
+    pub const fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the `User-Agent` header sent with each request.
+    #[must_use]
+    pub fn with_user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Set headers sent with each request.
+    #[must_use]
+    pub fn with_default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Add a proxy used for requests. Can be called several times.
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Use a pre-built blocking client instead of building one.
+    #[must_use]
+    pub fn with_client(mut self, client: reqwest::blocking::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Use a pre-built asynchronous client instead of building one.
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub fn with_async_client(mut self, client: reqwest::Client) -> Self {
+        self.async_client = Some(client);
+        self
+    }
+
+    /// Cache responses in `cache`, fresh for `ttl`. See [`cache`](crate::cache) module.
+    ///
+    /// Clients built by this builder and its clones share the same cache.
+    #[must_use]
+    pub fn with_cache(mut self, cache: impl Cache + 'static, ttl: Duration) -> Self {
+        self.pipeline.cache = Some(CacheLayer::new(Arc::new(cache), ttl));
+        self
+    }
+
+    /// Answer all requests from `snapshot`, without network. See [`snapshot`](crate::snapshot) module.
+    ///
+    /// Langages missing from the snapshot get "not found" errors.
+    #[must_use]
+    pub fn with_snapshot(mut self, snapshot: Snapshot) -> Self {
+        self.pipeline.snapshot = Some(Arc::new(snapshot));
+        self
+    }
+
+    /// Send again failed requests following `policy`. See [`retry`](crate::retry) module.
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.pipeline.retry = Some(policy);
+        self
+    }
+
+    /// Limit the request rate with `limiter`. See [`retry`](crate::retry) module.
+    ///
+    /// Clients built by this builder and its clones share the same limit. Retries count as requests.
+    #[must_use]
+    pub fn with_rate_limit(mut self, limiter: RateLimiter) -> Self {
+        self.pipeline.rate_limiter = Some(Arc::new(limiter));
+        self
+    }
+
+    /// Create the blocking [`Tcgdex`].
+    ///
+    /// # Errors
+    ///
+    /// If the HTTP client cannot be built (TLS backend failure for example).
+    pub fn build(self) -> errors::Result<Tcgdex> {
+        let client = match self.client {
+            Some(client) => client,
+            None => configure_client!(reqwest::blocking::Client::builder(), self).build()?,
+        };
+
+        Ok(Tcgdex {
+            client,
+            lang: self.lang,
+            base_url: self.base_url,
+            pipeline: self.pipeline,
+        })
+    }
+
+    /// Create the non-blocking `AsyncTcgdex`.
+    ///
+    /// # Errors
+    ///
+    /// If the HTTP client cannot be built (TLS backend failure for example).
+    #[cfg(feature = "async")]
+    pub fn build_async(self) -> errors::Result<crate::AsyncTcgdex> {
+        let client = match self.async_client {
+            Some(client) => client,
+            None => configure_client!(reqwest::Client::builder(), self).build()?,
+        };
+
+        Ok(crate::AsyncTcgdex {
+            client,
+            lang: self.lang,
+            base_url: self.base_url,
+            pipeline: self.pipeline,
+        })
+    }
+}
@@ -0,0 +1,310 @@
+//! Cache API responses using a [`Cache`] backend: [`MemoryCache`] or [`DiskCache`].
+//!
+//! A cache is set with [`TcgdexBuilder::with_cache`](crate::TcgdexBuilder::with_cache) and is used by every request.
+//!
+//! Responses are stored by langage and URL. A response younger than the TTL is used without request.
+//! An older one is revalidated with a conditional request (`If-None-Match` / `If-Modified-Since`):
+//! if the API answers `304 Not Modified`, the stored response is used again.
+//!
+//! Only successful responses are stored.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use tcgdex_api::cache::MemoryCache;
+//! # use tcgdex_api::TcgdexBuilder;
+//! # use std::time::Duration;
+//! // keep up to 1000 responses, fresh for one hour
+//! let tcgdex = TcgdexBuilder::new()
+//!     .with_cache(MemoryCache::new(1000), Duration::from_secs(3600))
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use crate::Lang;
+use reqwest::header::{
+    HeaderMap, HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Identify a stored response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    /// Langage of the response.
+    pub lang: Lang,
+
+    /// Requested URL.
+    pub url: String,
+}
+
+/// A stored response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// JSON body.
+    pub body: String,
+
+    /// `ETag` header of the response, if any.
+    pub etag: Option<String>,
+
+    /// `Last-Modified` header of the response, if any.
+    pub last_modified: Option<String>,
+
+    /// Time when the response was received or last revalidated.
+    pub stored_at: SystemTime,
+}
+
+/// A storage for responses.
+///
+/// A cache is best effort: a backend failing to store a response must not fail the request.
+pub trait Cache: Debug + Send + Sync {
+    /// Get the response stored for `key`, if any.
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse>;
+
+    /// Store `response` for `key`, replacing any previous one.
+    fn put(&self, key: &CacheKey, response: CachedResponse);
+}
+
+/// In-memory cache keeping the least recently used responses.
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+/// Stored responses with their last use, and their keys by last use, the least recently used first.
+#[derive(Debug, Default)]
+struct Entries {
+    responses: HashMap<CacheKey, (CachedResponse, u64)>,
+    usage: BTreeMap<u64, CacheKey>,
+    clock: u64,
+}
+
+impl Entries {
+    /// Mark the response of `key` as the most recently used.
+    fn touch(&mut self, key: &CacheKey) {
+        self.clock += 1;
+        if let Some((_, used)) = self.responses.get_mut(key) {
+            self.usage.remove(used);
+            *used = self.clock;
+            self.usage.insert(self.clock, key.clone());
+        }
+    }
+}
+
+impl MemoryCache {
+    /// Create a cache keeping up to `capacity` responses (at least one).
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Get the number of stored responses.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .responses
+            .len()
+    }
+
+    /// Returns true if no response is stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let (response, _) = entries.responses.get(key)?.clone();
+        entries.touch(key);
+        Some(response)
+    }
+
+    fn put(&self, key: &CacheKey, response: CachedResponse) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some((_, used)) = entries.responses.insert(key.clone(), (response, 0)) {
+            entries.usage.remove(&used);
+        }
+        entries.touch(key);
+        while entries.responses.len() > self.capacity {
+            match entries.usage.pop_first() {
+                Some((_, oldest)) => {
+                    entries.responses.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+/// On-disk cache storing each response as a JSON file in a directory.
+///
+/// Responses survive restarts and can be shared by several processes.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+/// Content of a cache file.
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    stored_at: u64,
+    body: String,
+}
+
+impl DiskCache {
+    /// Create a cache in `dir`. The directory is created if missing.
+    ///
+    /// # Errors
+    ///
+    /// If the directory cannot be created.
+    pub fn new(dir: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Get the file storing `key` response.
+    fn path(&self, key: &CacheKey) -> PathBuf {
+        let hash = fnv1a(key.url.as_bytes());
+        self.dir.join(format!("{}-{hash:016x}.json", key.lang))
+    }
+}
+
+impl Cache for DiskCache {
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        let content = std::fs::read(self.path(key)).ok()?;
+        let entry: DiskEntry = serde_json::from_slice(&content).ok()?;
+        // another URL with the same hash.
+        if entry.url != key.url {
+            return None;
+        }
+        Some(CachedResponse {
+            body: entry.body,
+            etag: entry.etag,
+            last_modified: entry.last_modified,
+            stored_at: UNIX_EPOCH + Duration::from_secs(entry.stored_at),
+        })
+    }
+
+    fn put(&self, key: &CacheKey, response: CachedResponse) {
+        let entry = DiskEntry {
+            url: key.url.clone(),
+            etag: response.etag,
+            last_modified: response.last_modified,
+            stored_at: response
+                .stored_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            body: response.body,
+        };
+        let Ok(content) = serde_json::to_vec(&entry) else {
+            return;
+        };
+        let _ = crate::write_atomically(&self.path(key), &content);
+    }
+}
+
+/// Hash `bytes` with FNV-1a: stable across builds, unlike std hashers.
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// State of a stored response.
+pub(crate) enum Lookup {
+    /// Younger than the TTL: used without request.
+    Fresh(CachedResponse),
+
+    /// Older than the TTL: to revalidate.
+    Stale(CachedResponse),
+
+    /// Nothing stored.
+    Missing,
+}
+
+/// A cache backend with its TTL, shared by clients.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheLayer {
+    backend: Arc<dyn Cache>,
+    ttl: Duration,
+}
+
+impl CacheLayer {
+    pub(crate) fn new(backend: Arc<dyn Cache>, ttl: Duration) -> Self {
+        Self { backend, ttl }
+    }
+
+    pub(crate) fn lookup(&self, key: &CacheKey) -> Lookup {
+        match self.backend.get(key) {
+            Some(response) => {
+                let age = response.stored_at.elapsed().unwrap_or_default();
+                if age < self.ttl {
+                    Lookup::Fresh(response)
+                } else {
+                    Lookup::Stale(response)
+                }
+            }
+            None => Lookup::Missing,
+        }
+    }
+
+    /// Store a successful response and get its body back.
+    pub(crate) fn store(&self, key: &CacheKey, headers: &HeaderMap, body: String) -> String {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|v: &HeaderValue| v.to_str().ok())
+                .map(ToString::to_string)
+        };
+        let response = CachedResponse {
+            body,
+            etag: header(ETAG),
+            last_modified: header(LAST_MODIFIED),
+            stored_at: SystemTime::now(),
+        };
+        self.backend.put(key, response.clone());
+        response.body
+    }
+
+    /// Mark a stale response as fresh again, after a `304 Not Modified`, and get its body.
+    pub(crate) fn revalidated(&self, key: &CacheKey, mut response: CachedResponse) -> String {
+        response.stored_at = SystemTime::now();
+        self.backend.put(key, response.clone());
+        response.body
+    }
+}
+
+/// Get the headers of a conditional request revalidating `response`.
+pub(crate) fn conditional_headers(response: &CachedResponse) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    let mut insert = |name, value: &Option<String>| {
+        if let Some(value) = value.as_deref().and_then(|v| HeaderValue::from_str(v).ok()) {
+            headers.insert(name, value);
+        }
+    };
+    insert(IF_NONE_MATCH, &response.etag);
+    insert(IF_MODIFIED_SINCE, &response.last_modified);
+    headers
+}
+
+/// Build a successful HTTP response with `body`, decoded like a network one.
+pub(crate) fn replay(body: String) -> http::Response<String> {
+    http::Response::new(body)
+}
@@ -0,0 +1,69 @@
+//! Export models in CBOR, a compact binary format, to store large card collections.
+//!
+//! All models can be exported: CBOR keeps the same structure as TCGdex JSON, unknown fields included.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use tcgdex_api::{cbor, CardBrief, Tcgdex};
+//! let tcgdex = Tcgdex::new();
+//! let cards: Vec<CardBrief> = tcgdex.cards().fetch(None).unwrap();
+//! let bytes = cbor::to_vec(&cards).unwrap();
+//! let cards: Vec<CardBrief> = cbor::from_slice(&bytes).unwrap();
+//! ```
+
+use crate::errors::CborError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io::{Read, Write};
+
+/// Write `value` in CBOR to `writer`.
+///
+/// # Errors
+///
+/// If `writer` fails.
+pub fn to_writer<T>(value: &T, writer: impl Write) -> Result<(), CborError>
+where
+    T: Serialize + ?Sized,
+{
+    ciborium::into_writer(value, writer)?;
+    Ok(())
+}
+
+/// Read a `T` in CBOR from `reader`.
+///
+/// # Errors
+///
+/// If `reader` fails or does not contain a `T`.
+pub fn from_reader<T>(reader: impl Read) -> Result<T, CborError>
+where
+    T: DeserializeOwned,
+{
+    Ok(ciborium::from_reader(reader)?)
+}
+
+/// Get `value` in CBOR.
+///
+/// # Errors
+///
+/// If `value` cannot be encoded.
+pub fn to_vec<T>(value: &T) -> Result<Vec<u8>, CborError>
+where
+    T: Serialize + ?Sized,
+{
+    let mut bytes = Vec::new();
+    to_writer(value, &mut bytes)?;
+    Ok(bytes)
+}
+
+/// Read a `T` in CBOR from `bytes`.
+///
+/// # Errors
+///
+/// If `bytes` does not contain a `T`.
+pub fn from_slice<T>(bytes: &[u8]) -> Result<T, CborError>
+where
+    T: DeserializeOwned,
+{
+    from_reader(bytes)
+}
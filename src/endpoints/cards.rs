@@ -1,16 +1,148 @@
 //! Get cards list using [`CardApi`].
 
-use crate::endpoints::sets::SetBrief;
+use crate::endpoints::categories::Category;
+use crate::endpoints::rarities::Rarity;
+use crate::endpoints::sets::{Legal, SetBrief};
+use crate::endpoints::types::PokemonType;
 use crate::errors;
 use crate::is_empty::IsEmpty;
-use serde::Deserialize;
+use crate::lenient::Lenient;
+use crate::pagination::Paginator;
+use crate::string_enum::string_enum;
+use crate::{Lang, Tcgdex};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 
-use crate::query::{Query, Response, URL_BASE};
+use crate::query::Query;
 
 const OBJECT_NAME: &str = "cards";
 
+string_enum! {
+    /// The Pokémon stage.
+    pub enum Stage {
+        /// Basic Pokémon.
+        Basic => "Basic",
+            FR: "Base",
+            DE: "Basis",
+            IT: "Base",
+            PT: "Básico",
+            ES: "Básico";
+
+        /// Stage 1 Pokémon.
+        Stage1 => "Stage1",
+            FR: "Niveau 1",
+            DE: "Phase 1",
+            IT: "Fase 1",
+            PT: "Estágio 1",
+            ES: "Fase 1";
+
+        /// Stage 2 Pokémon.
+        Stage2 => "Stage2",
+            FR: "Niveau 2",
+            DE: "Phase 2",
+            IT: "Fase 2",
+            PT: "Estágio 2",
+            ES: "Fase 2";
+
+        /// BREAK evolution.
+        Break => "BREAK";
+
+        /// LEVEL-UP Pokémon.
+        LevelUp => "LEVEL-UP";
+
+        /// Mega evolution.
+        Mega => "MEGA";
+
+        /// Restored Pokémon (from a fossil).
+        Restored => "RESTORED",
+            FR: "Restauré",
+            DE: "Wiederbelebt",
+            IT: "Rigenerato",
+            PT: "Restaurado",
+            ES: "Restaurado";
+
+        /// VMAX Pokémon.
+        Vmax => "VMAX";
+
+        /// VSTAR Pokémon.
+        Vstar => "VSTAR";
+
+        /// V-UNION Pokémon.
+        VUnion => "V-UNION";
+    }
+}
+
+string_enum! {
+    /// The type of an energy card.
+    pub enum EnergyType {
+        /// Basic energy.
+        Normal => "Normal",
+            IT: "Normale";
+
+        /// Special energy.
+        Special => "Special",
+            FR: "Spéciale",
+            DE: "Spezial",
+            IT: "Speciale",
+            PT: "Especial",
+            ES: "Especial";
+    }
+}
+
+string_enum! {
+    /// The type of a trainer card.
+    pub enum TrainerType {
+        /// Item card.
+        Item => "Item",
+            FR: "Objet",
+            IT: "Strumento",
+            ES: "Objeto";
+
+        /// Supporter card.
+        Supporter => "Supporter",
+            DE: "Unterstützer",
+            IT: "Aiuto",
+            PT: "Apoiador",
+            ES: "Partidario";
+
+        /// Stadium card.
+        Stadium => "Stadium",
+            FR: "Stade",
+            DE: "Stadion",
+            IT: "Stadio",
+            PT: "Estádio",
+            ES: "Estadio";
+
+        /// Pokémon Tool card.
+        Tool => "Tool",
+            FR: "Outil",
+            DE: "Ausrüstung",
+            IT: "Oggetto",
+            PT: "Ferramenta",
+            ES: "Herramienta";
+
+        /// ACE SPEC card.
+        AceSpec => "Ace Spec";
+
+        /// Technical Machine card.
+        TechnicalMachine => "Technical Machine",
+            FR: "Machine Technique",
+            DE: "Technische Maschine",
+            IT: "Macchina Tecnica",
+            PT: "Máquina Técnica",
+            ES: "Máquina Técnica";
+
+        /// Rocket's Secret Machine card.
+        RocketsSecretMachine => "Rocket's Secret Machine";
+
+        /// Goldenrod Game Corner card.
+        GoldenrodGameCorner => "Goldenrod Game Corner";
+    }
+}
+
 /// The possible variants of this card.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq, Copy, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Copy, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Variants {
     /// Card available without any shines.
@@ -24,24 +156,151 @@ pub struct Variants {
 
     /// Card has a small 1st edition in the middle left.
     pub first_edition: bool,
+
+    /// Card is a Wizards of the Coast black star promo.
+    #[serde(default)]
+    pub w_promo: bool,
+}
+
+/// Details about one variant of this card.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct VariantDetail {
+    /// The variant type, like `normal`, `reverse` or `holo`.
+    #[serde(rename = "type")]
+    pub _type: String,
+
+    /// The card size, like `standard` or `jumbo`.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub size: String,
+
+    /// The stamps printed on this variant.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stamp: Vec<String>,
+
+    /// The foil pattern of this variant.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub foil: String,
+}
+
+/// A booster in which this card can be found.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct Booster {
+    /// Unique identifier for the booster.
+    pub id: String,
+
+    /// The name of the booster.
+    pub name: String,
+
+    /// The url to the booster logo.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub logo: String,
+
+    /// The url to the front artwork of the booster.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub artwork_front: String,
+
+    /// The url to the back artwork of the booster.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub artwork_back: String,
+}
+
+/// The way attack damages can change according to the attack effect.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub enum DamageModifier {
+    /// Damages are fixed, like `30`.
+    #[default]
+    None,
+
+    /// Damages can be increased, like `30+`.
+    Plus,
+
+    /// Damages are multiplied, like `20×`.
+    Times,
+
+    /// Damages can be decreased, like `50-`.
+    Minus,
+}
+
+/// Attack damages: a base value with an optional modifier.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Copy, Clone)]
+#[serde(from = "RawDamage", into = "RawDamage")]
+pub struct Damage {
+    /// The base value of damages.
+    pub value: u16,
+
+    /// How damages change according to the attack effect.
+    pub modifier: DamageModifier,
+}
+
+/// Damages as returned by TCGdex API: a number or a string like `30+`.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawDamage {
+    Number(u16),
+    Text(String),
+}
+
+impl From<RawDamage> for Damage {
+    fn from(raw: RawDamage) -> Self {
+        match raw {
+            RawDamage::Number(value) => Self {
+                value,
+                modifier: DamageModifier::None,
+            },
+            RawDamage::Text(text) => {
+                let text = text.trim();
+                let modifier = match text.chars().last() {
+                    Some('+') => DamageModifier::Plus,
+                    Some('×' | 'x' | 'X') => DamageModifier::Times,
+                    Some('-') => DamageModifier::Minus,
+                    _ => DamageModifier::None,
+                };
+                let digits: String = text.chars().take_while(char::is_ascii_digit).collect();
+                Self {
+                    value: digits.parse().unwrap_or_default(),
+                    modifier,
+                }
+            }
+        }
+    }
+}
+
+impl From<Damage> for RawDamage {
+    fn from(damage: Damage) -> Self {
+        let modifier = match damage.modifier {
+            DamageModifier::None => return Self::Number(damage.value),
+            DamageModifier::Plus => '+',
+            DamageModifier::Times => '×',
+            DamageModifier::Minus => '-',
+        };
+        Self::Text(format!("{}{modifier}", damage.value))
+    }
 }
 
 /// Pokémon attack information.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
 #[serde(default)]
 pub struct Attack {
+    /// The energies needed to use the attack.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cost: Vec<PokemonType>,
+
     /// The name of the attack.
     pub name: String,
 
     /// The effect of the attack.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub effect: String,
 
     /// The damages of the attack.
-    pub damage: u16,
+    #[serde(skip_serializing_if = "crate::is_default")]
+    pub damage: Damage,
 }
 
 /// The Pokémon item.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
 #[serde(default)]
 pub struct Item {
     /// The Item name.
@@ -51,19 +310,48 @@ pub struct Item {
     pub effect: String,
 }
 
+/// Pokémon ability information.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Ability {
+    /// The ability type, like `Ability` or `Poke-POWER`.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub _type: String,
+
+    /// The ability name.
+    pub name: String,
+
+    /// The ability effect.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub effect: String,
+}
+
 /// Weaknesses information, only for Pokémon cards.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
 #[serde(default, rename_all = "camelCase")]
 pub struct Weakness {
     /// The weakness type.
-    pub _type: String,
+    pub _type: PokemonType,
 
     /// The weakness value.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub value: String,
+}
+
+/// Resistances information, only for Pokémon cards.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Resistance {
+    /// The resistance type.
+    pub _type: PokemonType,
+
+    /// The resistance value.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub value: String,
 }
 
 /// Brief information about the card.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct CardBrief {
     /// Unique identifier for the object.
@@ -76,12 +364,12 @@ pub struct CardBrief {
     pub name: String,
 
     /// The url to the card image.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub image: String,
 }
 
 /// Information about the card.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
 #[serde(default, rename_all = "camelCase")]
 pub struct Card {
     /// Unique identifier for the object.
@@ -94,70 +382,127 @@ pub struct Card {
     pub name: String,
 
     /// The url to the card image.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub image: String,
 
     /// Card category.
-    pub category: String,
+    pub category: Category,
 
     /// Card illustrator.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub illustrator: String,
 
     /// Card rarity.
-    pub rarity: String,
+    pub rarity: Rarity,
 
     /// The possible variants of this card.
+    #[serde(skip_serializing_if = "crate::is_default")]
     pub variants: Variants,
 
+    /// Details about each variant of this card.
+    #[serde(rename = "variants_detailed", skip_serializing_if = "Vec::is_empty")]
+    pub variants_detailed: Vec<VariantDetail>,
+
     /// Basic information about the card set.
     pub set: SetBrief,
 
+    /// The boosters in which this card can be found.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub boosters: Vec<Booster>,
+
+    /// Card usability in competitions.
+    pub legal: Legal,
+
+    /// The last update date of the card, in RFC 3339 format.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub updated: String,
+
     // Next fields are only for Pokémon cards.
     /// The National Pokedex ID of the Pokémon on the card.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub dex_id: Vec<u16>,
 
     /// The Pokémon HP.
+    #[serde(skip_serializing_if = "crate::is_default")]
     pub hp: u16,
 
     /// The types of the Pokémon.
-    pub types: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<PokemonType>,
 
     /// The Pokémon name it evolve from.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub evolve_from: String,
 
     /// The card description.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub description: String,
 
     /// The Pokémon level (if it’s a lv.X the level is X).
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub level: String,
 
     /// The Pokémon stage.
-    pub stage: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<Stage>,
 
     /// The card suffix.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub suffix: String,
 
     /// The Pokémon item.
+    #[serde(skip_serializing_if = "crate::is_default")]
     pub item: Item,
 
+    /// The Pokémon abilities.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub abilities: Vec<Ability>,
+
     /// The Pokémon attacks.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub attacks: Vec<Attack>,
 
     /// The Pokémon weaknesses.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub weaknesses: Vec<Weakness>,
 
+    /// The Pokémon resistances.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub resistances: Vec<Resistance>,
+
+    /// The Pokémon retreat cost.
+    #[serde(skip_serializing_if = "crate::is_default")]
+    pub retreat: u8,
+
     /// The Pokémon regulation mark.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub regulation_mark: String,
 
     // Next fields are only for Trainer cards.
     /// The trainer card effect.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub effect: String,
 
     /// The type of trainer card.
-    pub trainer_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trainer_type: Option<TrainerType>,
 
     // Next fields are only for Energy cards.
     /// The type of energy card.
-    pub energy_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub energy_type: Option<EnergyType>,
+
+    /// Fields unknown to this library, as returned by the API.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Cards matching a value of a list endpoint, like `/types/Fire`.
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub(crate) struct CardsFor {
+    /// The cards with this value.
+    pub(crate) cards: Vec<CardBrief>,
 }
 
 impl IsEmpty for Card {
@@ -174,9 +519,9 @@ impl IsEmpty for Vec<CardBrief> {
 
 /// Gives access to module functions.
 #[derive(Debug)]
-pub struct CardApi<'a>(pub(crate) &'a reqwest::blocking::Client, pub(crate) String);
+pub struct CardApi<'a, C = Tcgdex>(pub(crate) &'a C);
 
-impl CardApi<'_> {
+impl<'t> CardApi<'t> {
     /// Get cards.
     ///
     /// # Argument
@@ -185,7 +530,7 @@ impl CardApi<'_> {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use tcgdex_api::{Tcgdex, Lang};
     /// use tcgdex_api::endpoints::cards::CardBrief;
     /// let tcgdex = Tcgdex::new();
@@ -195,31 +540,233 @@ impl CardApi<'_> {
     ///
     /// # Errors
     ///
-    /// If TCGDEX API is updated with URL or JSON format modifications.
+    /// If TCGDEX API is updated with URL or JSON format modifications,
+    /// or if the query is invalid (see [`Query::validate`]).
     pub fn fetch<T>(&self, query: Option<&Query>) -> errors::Result<T>
     where
         T: for<'a> Deserialize<'a> + IsEmpty,
     {
-        let client: &reqwest::blocking::Client = self.0;
-        let mut url_query = String::new();
-        let mut separator = String::from("/");
-
-        // if query is used.
-        if let Some(f) = query {
-            url_query = f.to_string();
+        if let Some(query) = query {
+            query.validate()?;
         }
+        let url = self.0.url(OBJECT_NAME, query);
+        self.0.get(&url).and_then(errors::non_empty)
+    }
 
-        // if query is used to filtering, sorting or pagination.
-        if url_query.contains('&') || url_query.contains('=') {
-            separator = String::from('?');
+    /// Get a lazy iterator over all cards, requesting pages of `items_per_page` cards.
+    ///
+    /// # Arguments
+    ///
+    /// `query` - A [`Query`] used to filter or sort cards. Its pagination is replaced.
+    /// `items_per_page` - Number of cards requested at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// for item in tcgdex.cards().paginate(None, 100) {
+    ///     println!("cards = {:?}", item.unwrap());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn paginate(&self, query: Option<&Query>, items_per_page: u16) -> Paginator<'t, CardBrief> {
+        Paginator::new(self.0, OBJECT_NAME, query, items_per_page)
+    }
+
+    /// Get the card `id` in each langage of `langs`.
+    ///
+    /// Requests are sent concurrently. Langages in which the card does not exist are not in the returned map.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{Tcgdex, Lang};
+    /// let tcgdex = Tcgdex::new();
+    /// let cards = tcgdex.cards().fetch_langs("swsh3-136", &[Lang::EN, Lang::FR]).unwrap();
+    /// println!("card names = {:?}", cards.values().map(|v| &v.name).collect::<Vec<_>>());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If a request fails for another reason than a missing card.
+    pub fn fetch_langs(&self, id: &str, langs: &[Lang]) -> errors::Result<HashMap<Lang, Card>> {
+        self.0.fetch_langs(OBJECT_NAME, id, langs)
+    }
+
+    /// Get the card `id`, decoded leniently: see [`lenient`](crate::lenient) module.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// let card = tcgdex.cards().fetch_lenient("swsh3-136").unwrap();
+    /// println!("warnings = {:?}", card.warnings);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the request fails, or if the response is not a card.
+    pub fn fetch_lenient(&self, id: &str) -> errors::Result<Lenient<Card>> {
+        self.0.fetch_lenient(OBJECT_NAME, id)
+    }
+
+    /// Get the cards `ids`, requesting several cards at once.
+    ///
+    /// Duplicated ids are requested once. Results are in order of first occurrence of their id,
+    /// each with its own result: a missing card does not prevent getting the others.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// for (id, card) in tcgdex.cards().fetch_many(&["swsh3-136", "swsh3-20", "swsh3-136"]) {
+    ///     println!("{id} = {:?}", card);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn fetch_many(&self, ids: &[impl AsRef<str>]) -> Vec<(String, errors::Result<Card>)> {
+        self.0.fetch_many(OBJECT_NAME, ids)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'t> CardApi<'t, crate::AsyncTcgdex> {
+    /// Get cards.
+    ///
+    /// # Argument
+    ///
+    /// `query` - A [`Query`] used to get cards. If None, returns all cards.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// use tcgdex_api::endpoints::cards::CardBrief;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let cards: Vec<CardBrief> = tcgdex.cards().fetch(None).await.unwrap();
+    /// println!("cards = {:?}", cards);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications,
+    /// or if the query is invalid (see [`Query::validate`]).
+    pub async fn fetch<T>(&self, query: Option<&Query>) -> errors::Result<T>
+    where
+        T: for<'a> Deserialize<'a> + IsEmpty,
+    {
+        if let Some(query) = query {
+            query.validate()?;
         }
+        let url = self.0.url(OBJECT_NAME, query);
+        self.0.get(&url).await.and_then(errors::non_empty)
+    }
+
+    /// Get a lazy stream over all cards, requesting pages of `items_per_page` cards.
+    ///
+    /// # Arguments
+    ///
+    /// `query` - A [`Query`] used to filter or sort cards. Its pagination is replaced.
+    /// `items_per_page` - Number of cards requested at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// use futures_util::StreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let mut cards = std::pin::pin!(tcgdex.cards().paginate(None, 100));
+    /// while let Some(item) = cards.next().await {
+    ///     println!("cards = {:?}", item.unwrap());
+    /// }
+    /// # }
+    /// ```
+    pub fn paginate(
+        &self,
+        query: Option<&Query>,
+        items_per_page: u16,
+    ) -> impl futures_util::Stream<Item = errors::Result<CardBrief>> + 't {
+        crate::pagination::stream(self.0, OBJECT_NAME, query, items_per_page)
+    }
+
+    /// Get the card `id` in each langage of `langs`.
+    ///
+    /// Requests are sent concurrently. Langages in which the card does not exist are not in the returned map.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{AsyncTcgdex, Lang};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let cards = tcgdex
+    ///     .cards()
+    ///     .fetch_langs("swsh3-136", &[Lang::EN, Lang::FR])
+    ///     .await
+    ///     .unwrap();
+    /// println!("card names = {:?}", cards.values().map(|v| &v.name).collect::<Vec<_>>());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If a request fails for another reason than a missing card.
+    pub async fn fetch_langs(
+        &self,
+        id: &str,
+        langs: &[Lang],
+    ) -> errors::Result<HashMap<Lang, Card>> {
+        self.0.fetch_langs(OBJECT_NAME, id, langs).await
+    }
 
-        let url = format!(
-            "{}{}/{OBJECT_NAME}{}{url_query}",
-            URL_BASE, self.1, separator
-        );
+    /// Get the card `id`, decoded leniently: see [`lenient`](crate::lenient) module.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let card = tcgdex.cards().fetch_lenient("swsh3-136").await.unwrap();
+    /// println!("warnings = {:?}", card.warnings);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the request fails, or if the response is not a card.
+    pub async fn fetch_lenient(&self, id: &str) -> errors::Result<Lenient<Card>> {
+        self.0.fetch_lenient(OBJECT_NAME, id).await
+    }
 
-        let response: Response<T> = client.get(url).send()?.json()?;
-        errors::set_error(response)
+    /// Get the cards `ids`, requesting several cards at once.
+    ///
+    /// Duplicated ids are requested once. Results are in order of first occurrence of their id,
+    /// each with its own result: a missing card does not prevent getting the others.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// for (id, card) in tcgdex.cards().fetch_many(&["swsh3-136", "swsh3-20", "swsh3-136"]).await {
+    ///     println!("{id} = {:?}", card);
+    /// }
+    /// # }
+    /// ```
+    pub async fn fetch_many(&self, ids: &[impl AsRef<str>]) -> Vec<(String, errors::Result<Card>)> {
+        self.0.fetch_many(OBJECT_NAME, ids).await
     }
 }
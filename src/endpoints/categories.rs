@@ -2,20 +2,51 @@
 //!
 //! Category is like Pokémon or trainer for example.
 
-use crate::query::URL_BASE;
+use crate::endpoints::cards::CardBrief;
+use crate::errors;
+use crate::string_enum::{string_enum, RequestName};
+use crate::Tcgdex;
 
 const OBJECT_NAME: &str = "categories";
 
+string_enum! {
+    /// A card category.
+    pub enum Category {
+        /// Pokémon card.
+        Pokemon => "Pokemon",
+            FR: "Pokémon",
+            DE: "Pokémon",
+            IT: "Pokémon",
+            PT: "Pokémon",
+            ES: "Pokémon";
+
+        /// Trainer card.
+        Trainer => "Trainer",
+            FR: "Dresseur",
+            IT: "Allenatore",
+            PT: "Treinador",
+            ES: "Entrenador";
+
+        /// Energy card.
+        Energy => "Energy",
+            FR: "Énergie",
+            DE: "Energie",
+            IT: "Energia",
+            PT: "Energia",
+            ES: "Energía";
+    }
+}
+
 /// Gives access to module functions.
 #[derive(Debug)]
-pub struct CategoryApi<'a>(pub(crate) &'a reqwest::blocking::Client, pub(crate) String);
+pub struct CategoryApi<'a, C = Tcgdex>(pub(crate) &'a C);
 
 impl CategoryApi<'_> {
     /// Get all existing Pokémon categories.
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use tcgdex_api::{Tcgdex, Lang};
     /// let tcgdex = Tcgdex::new();
     /// println!("categories = {:?}", tcgdex.categories().fetch().unwrap());
@@ -24,12 +55,82 @@ impl CategoryApi<'_> {
     /// # Errors
     ///
     /// If TCGDEX API is updated with URL or JSON format modifications.
-    pub fn fetch(&self) -> reqwest::Result<Vec<String>> {
-        let client: &reqwest::blocking::Client = self.0;
-        let categories: Vec<String> = client
-            .get(format!("{}{}/{OBJECT_NAME}", URL_BASE, self.1))
-            .send()?
-            .json()?;
+    pub fn fetch(&self) -> errors::Result<Vec<Category>> {
+        let categories: Vec<Category> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
         Ok(categories)
     }
+
+    /// Get the cards of `value` category.
+    ///
+    /// The category is requested by its name in the client langage.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// use tcgdex_api::Category;
+    /// let tcgdex = Tcgdex::new();
+    /// println!("cards = {:?}", tcgdex.categories().cards_for(&Category::Trainer).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, if its names in the client langage are not known
+    /// (see [`Lang::has_value_names`](crate::Lang::has_value_names)),
+    /// or if TCGDEX API is updated with URL or JSON format modifications.
+    pub fn cards_for(&self, value: &Category) -> errors::Result<Vec<CardBrief>> {
+        self.0
+            .cards_for(OBJECT_NAME, value.request_name(self.0.lang)?)
+    }
+}
+
+#[cfg(feature = "async")]
+impl CategoryApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing Pokémon categories.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("categories = {:?}", tcgdex.categories().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<Category>> {
+        let categories: Vec<Category> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(categories)
+    }
+
+    /// Get the cards of `value` category.
+    ///
+    /// The category is requested by its name in the client langage.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// use tcgdex_api::Category;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("cards = {:?}", tcgdex.categories().cards_for(&Category::Trainer).await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, if its names in the client langage are not known
+    /// (see [`Lang::has_value_names`](crate::Lang::has_value_names)),
+    /// or if TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn cards_for(&self, value: &Category) -> errors::Result<Vec<CardBrief>> {
+        self.0
+            .cards_for(OBJECT_NAME, value.request_name(self.0.lang)?)
+            .await
+    }
 }
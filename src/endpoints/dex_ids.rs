@@ -0,0 +1,54 @@
+//! Get National Pokédex ids list using [`DexIdApi`].
+
+use crate::errors;
+use crate::Tcgdex;
+
+const OBJECT_NAME: &str = "dex-ids";
+
+/// Gives access to module functions.
+#[derive(Debug)]
+pub struct DexIdApi<'a, C = Tcgdex>(pub(crate) &'a C);
+
+impl DexIdApi<'_> {
+    /// Get all National Pokédex ids used by cards.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{Tcgdex, Lang};
+    /// let tcgdex = Tcgdex::new();
+    /// println!("dex_ids = {:?}", tcgdex.dex_ids().fetch().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub fn fetch(&self) -> errors::Result<Vec<u16>> {
+        let dex_ids: Vec<u16> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
+        Ok(dex_ids)
+    }
+}
+
+#[cfg(feature = "async")]
+impl DexIdApi<'_, crate::AsyncTcgdex> {
+    /// Get all National Pokédex ids used by cards.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("dex_ids = {:?}", tcgdex.dex_ids().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<u16>> {
+        let dex_ids: Vec<u16> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(dex_ids)
+    }
+}
@@ -0,0 +1,57 @@
+//! Get energy types list using [`EnergyTypeApi`].
+//!
+//! Energy type is normal or special.
+
+use crate::endpoints::cards::EnergyType;
+use crate::errors;
+use crate::Tcgdex;
+
+const OBJECT_NAME: &str = "energy-types";
+
+/// Gives access to module functions.
+#[derive(Debug)]
+pub struct EnergyTypeApi<'a, C = Tcgdex>(pub(crate) &'a C);
+
+impl EnergyTypeApi<'_> {
+    /// Get all existing energy card types.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{Tcgdex, Lang};
+    /// let tcgdex = Tcgdex::new();
+    /// println!("energy_types = {:?}", tcgdex.energy_types().fetch().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub fn fetch(&self) -> errors::Result<Vec<EnergyType>> {
+        let energy_types: Vec<EnergyType> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
+        Ok(energy_types)
+    }
+}
+
+#[cfg(feature = "async")]
+impl EnergyTypeApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing energy card types.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("energy_types = {:?}", tcgdex.energy_types().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<EnergyType>> {
+        let energy_types: Vec<EnergyType> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(energy_types)
+    }
+}
@@ -1,19 +1,21 @@
 //! Get HP list using [`HpApi`].
 
-use crate::query::URL_BASE;
+use crate::endpoints::cards::CardBrief;
+use crate::errors;
+use crate::Tcgdex;
 
 const OBJECT_NAME: &str = "hp";
 
 /// Gives access to module functions.
 #[derive(Debug)]
-pub struct HpApi<'a>(pub(crate) &'a reqwest::blocking::Client, pub(crate) String);
+pub struct HpApi<'a, C = Tcgdex>(pub(crate) &'a C);
 
 impl HpApi<'_> {
     /// Get all existing Pokémon HP.
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use tcgdex_api::{Tcgdex, Lang};
     /// let tcgdex = Tcgdex::new();
     /// println!("hps = {:?}", tcgdex.hps().fetch().unwrap());
@@ -22,12 +24,69 @@ impl HpApi<'_> {
     /// # Errors
     ///
     /// If TCGDEX API is updated with URL or JSON format modifications.
-    pub fn fetch(&self) -> reqwest::Result<Vec<u16>> {
-        let client: &reqwest::blocking::Client = self.0;
-        let hps: Vec<u16> = client
-            .get(format!("{}{}/{OBJECT_NAME}", URL_BASE, self.1))
-            .send()?
-            .json()?;
+    pub fn fetch(&self) -> errors::Result<Vec<u16>> {
+        let hps: Vec<u16> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
         Ok(hps)
     }
+
+    /// Get the cards of Pokémon with `hp` HP.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// println!("cards = {:?}", tcgdex.hps().cards_for(60).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, or if TCGDEX API is updated with URL or JSON format modifications.
+    pub fn cards_for(&self, hp: u16) -> errors::Result<Vec<CardBrief>> {
+        self.0.cards_for(OBJECT_NAME, &hp.to_string())
+    }
+}
+
+#[cfg(feature = "async")]
+impl HpApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing Pokémon HP.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("hps = {:?}", tcgdex.hps().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<u16>> {
+        let hps: Vec<u16> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(hps)
+    }
+
+    /// Get the cards of Pokémon with `hp` HP.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("cards = {:?}", tcgdex.hps().cards_for(60).await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, or if TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn cards_for(&self, hp: u16) -> errors::Result<Vec<CardBrief>> {
+        self.0.cards_for(OBJECT_NAME, &hp.to_string()).await
+    }
 }
@@ -1,19 +1,21 @@
 //! Get illustrators list using [`IllustratorApi`].
 
-use crate::query::URL_BASE;
+use crate::endpoints::cards::CardBrief;
+use crate::errors;
+use crate::Tcgdex;
 
 const OBJECT_NAME: &str = "illustrators";
 
 /// Gives access to module functions.
 #[derive(Debug)]
-pub struct IllustratorApi<'a>(pub(crate) &'a reqwest::blocking::Client, pub(crate) String);
+pub struct IllustratorApi<'a, C = Tcgdex>(pub(crate) &'a C);
 
 impl IllustratorApi<'_> {
     /// Get all existing Pokémon illustrators.
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use tcgdex_api::{Tcgdex, Lang};
     /// let tcgdex = Tcgdex::new();
     /// println!("illustrators = {:?}", tcgdex.illustrators().fetch().unwrap());
@@ -22,12 +24,73 @@ impl IllustratorApi<'_> {
     /// # Errors
     ///
     /// If TCGDEX API is updated with URL or JSON format modifications.
-    pub fn fetch(&self) -> reqwest::Result<Vec<String>> {
-        let client: &reqwest::blocking::Client = self.0;
-        let illustrators: Vec<String> = client
-            .get(format!("{}{}/{OBJECT_NAME}", URL_BASE, self.1))
-            .send()?
-            .json()?;
+    pub fn fetch(&self) -> errors::Result<Vec<String>> {
+        let illustrators: Vec<String> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
         Ok(illustrators)
     }
+
+    /// Get the cards drawn by `name` illustrator.
+    ///
+    /// The name is URL encoded: spaces and other special characters are allowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// println!("cards = {:?}", tcgdex.illustrators().cards_for("Ken Sugimori").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, or if TCGDEX API is updated with URL or JSON format modifications.
+    pub fn cards_for(&self, name: &str) -> errors::Result<Vec<CardBrief>> {
+        self.0.cards_for(OBJECT_NAME, name)
+    }
+}
+
+#[cfg(feature = "async")]
+impl IllustratorApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing Pokémon illustrators.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("illustrators = {:?}", tcgdex.illustrators().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<String>> {
+        let illustrators: Vec<String> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(illustrators)
+    }
+
+    /// Get the cards drawn by `name` illustrator.
+    ///
+    /// The name is URL encoded: spaces and other special characters are allowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("cards = {:?}", tcgdex.illustrators().cards_for("Ken Sugimori").await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, or if TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn cards_for(&self, name: &str) -> errors::Result<Vec<CardBrief>> {
+        self.0.cards_for(OBJECT_NAME, name).await
+    }
 }
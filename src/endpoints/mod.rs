@@ -1,10 +1,17 @@
 //! Module containing all endpoints reachable with a query.
 pub mod cards;
 pub mod categories;
+pub mod dex_ids;
+pub mod energy_types;
 pub mod hps;
 pub mod illustrators;
 pub mod rarities;
+pub mod regulation_marks;
 pub mod retreats;
 pub mod series;
 pub mod sets;
+pub mod stages;
+pub mod suffixes;
+pub mod trainer_types;
 pub mod types;
+pub mod variants;
@@ -2,20 +2,188 @@
 //!
 //! Rarity is like common or rare for example.
 
-use crate::query::URL_BASE;
+use crate::endpoints::cards::CardBrief;
+use crate::errors;
+use crate::string_enum::{string_enum, RequestName};
+use crate::Tcgdex;
 
 const OBJECT_NAME: &str = "rarities";
 
+string_enum! {
+    /// A card rarity.
+    pub enum Rarity {
+        /// ACE SPEC rarity.
+        AceSpecRare => "ACE SPEC Rare";
+
+        /// Amazing rare.
+        Amazing => "Amazing";
+
+        /// Classic Collection reprint.
+        ClassicCollection => "Classic Collection";
+
+        /// Common card.
+        Common => "Common",
+            FR: "Commune",
+            DE: "Häufig",
+            IT: "Comune",
+            PT: "Comum",
+            ES: "Común";
+
+        /// Crown rare (Pokémon TCG Pocket).
+        Crown => "Crown";
+
+        /// Double rare.
+        DoubleRare => "Double rare",
+            DE: "Doppelselten",
+            IT: "Doppia rara",
+            PT: "Dupla rara",
+            ES: "Doble rara";
+
+        /// Full art trainer.
+        FullArtTrainer => "Full Art Trainer";
+
+        /// Holo rare.
+        HoloRare => "Holo Rare";
+
+        /// Holo rare V.
+        HoloRareV => "Holo Rare V";
+
+        /// Holo rare VMAX.
+        HoloRareVmax => "Holo Rare VMAX";
+
+        /// Holo rare VSTAR.
+        HoloRareVstar => "Holo Rare VSTAR";
+
+        /// Hyper rare.
+        HyperRare => "Hyper rare",
+            DE: "Hyper selten",
+            IT: "Iper rara",
+            PT: "Hiper-rara",
+            ES: "Hiperrara";
+
+        /// Illustration rare.
+        IllustrationRare => "Illustration rare",
+            DE: "Illustration selten",
+            IT: "Rara illustrazione",
+            PT: "Rara ilustração",
+            ES: "Rara ilustración";
+
+        /// LEGEND card.
+        Legend => "LEGEND";
+
+        /// Card without rarity.
+        None => "None",
+            FR: "Sans Rareté",
+            DE: "Keine",
+            IT: "Nessuna",
+            PT: "Nenhuma",
+            ES: "Ninguna";
+
+        /// Radiant rare.
+        RadiantRare => "Radiant Rare";
+
+        /// Rare card.
+        Rare => "Rare",
+            DE: "Selten",
+            IT: "Rara",
+            PT: "Rara",
+            ES: "Rara";
+
+        /// Rare holo.
+        RareHolo => "Rare Holo",
+            DE: "Selten, Holo",
+            IT: "Rara Holo",
+            PT: "Rara Holo",
+            ES: "Rara Holo";
+
+        /// Rare holo LV.X.
+        RareHoloLvX => "Rare Holo LV.X";
+
+        /// Rare PRIME.
+        RarePrime => "Rare PRIME";
+
+        /// Secret rare.
+        SecretRare => "Secret Rare",
+            FR: "Rare Secrète",
+            DE: "Geheim selten",
+            IT: "Rara Segreta",
+            PT: "Rara Secreta",
+            ES: "Rara Secreta";
+
+        /// Shiny ultra rare.
+        ShinyUltraRare => "Shiny Ultra Rare";
+
+        /// Shiny rare.
+        ShinyRare => "Shiny rare";
+
+        /// Shiny rare V.
+        ShinyRareV => "Shiny rare V";
+
+        /// Shiny rare VMAX.
+        ShinyRareVmax => "Shiny rare VMAX";
+
+        /// Special illustration rare.
+        SpecialIllustrationRare => "Special illustration rare",
+            FR: "Illustration spéciale rare",
+            DE: "Besondere Illustration selten",
+            IT: "Rara illustrazione speciale",
+            PT: "Rara ilustração especial",
+            ES: "Rara ilustración especial";
+
+        /// Ultra rare.
+        UltraRare => "Ultra Rare",
+            DE: "Ultra selten",
+            IT: "Ultra Rara",
+            PT: "Ultra Rara",
+            ES: "Ultra Rara";
+
+        /// Uncommon card.
+        Uncommon => "Uncommon",
+            FR: "Peu Commune",
+            DE: "Nicht so häufig",
+            IT: "Non Comune",
+            PT: "Incomum",
+            ES: "Infrecuente";
+
+        /// One diamond (Pokémon TCG Pocket).
+        OneDiamond => "One Diamond";
+
+        /// Two diamonds (Pokémon TCG Pocket).
+        TwoDiamond => "Two Diamond";
+
+        /// Three diamonds (Pokémon TCG Pocket).
+        ThreeDiamond => "Three Diamond";
+
+        /// Four diamonds (Pokémon TCG Pocket).
+        FourDiamond => "Four Diamond";
+
+        /// One star (Pokémon TCG Pocket).
+        OneStar => "One Star";
+
+        /// Two stars (Pokémon TCG Pocket).
+        TwoStar => "Two Star";
+
+        /// Three stars (Pokémon TCG Pocket).
+        ThreeStar => "Three Star";
+
+        /// One shiny (Pokémon TCG Pocket).
+        OneShiny => "One Shiny";
+
+        /// Two shinies (Pokémon TCG Pocket).
+        TwoShiny => "Two Shiny";
+    }
+}
+
 /// Gives access to module functions.
 #[derive(Debug)]
-pub struct RarityApi<'a>(pub(crate) &'a reqwest::blocking::Client, pub(crate) String);
+pub struct RarityApi<'a, C = Tcgdex>(pub(crate) &'a C);
 
 impl RarityApi<'_> {
     /// Get all existing Pokémon rarities.
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use tcgdex_api::{Tcgdex, Lang};
     /// let tcgdex = Tcgdex::new();
     /// println!("rarities = {:?}", tcgdex.rarities().fetch().unwrap());
@@ -24,12 +192,82 @@ impl RarityApi<'_> {
     /// # Errors
     ///
     /// If TCGDEX API is updated with URL or JSON format modifications.
-    pub fn fetch(&self) -> reqwest::Result<Vec<String>> {
-        let client: &reqwest::blocking::Client = self.0;
-        let rarities: Vec<String> = client
-            .get(format!("{}{}/{OBJECT_NAME}", URL_BASE, self.1))
-            .send()?
-            .json()?;
+    pub fn fetch(&self) -> errors::Result<Vec<Rarity>> {
+        let rarities: Vec<Rarity> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
+        Ok(rarities)
+    }
+
+    /// Get the cards of `value` rarity.
+    ///
+    /// The rarity is requested by its name in the client langage.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// use tcgdex_api::Rarity;
+    /// let tcgdex = Tcgdex::new();
+    /// println!("cards = {:?}", tcgdex.rarities().cards_for(&Rarity::Common).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, if its names in the client langage are not known
+    /// (see [`Lang::has_value_names`](crate::Lang::has_value_names)),
+    /// or if TCGDEX API is updated with URL or JSON format modifications.
+    pub fn cards_for(&self, value: &Rarity) -> errors::Result<Vec<CardBrief>> {
+        self.0
+            .cards_for(OBJECT_NAME, value.request_name(self.0.lang)?)
+    }
+}
+
+#[cfg(feature = "async")]
+impl RarityApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing Pokémon rarities.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("rarities = {:?}", tcgdex.rarities().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<Rarity>> {
+        let rarities: Vec<Rarity> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
         Ok(rarities)
     }
+
+    /// Get the cards of `value` rarity.
+    ///
+    /// The rarity is requested by its name in the client langage.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// use tcgdex_api::Rarity;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("cards = {:?}", tcgdex.rarities().cards_for(&Rarity::Common).await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, if its names in the client langage are not known
+    /// (see [`Lang::has_value_names`](crate::Lang::has_value_names)),
+    /// or if TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn cards_for(&self, value: &Rarity) -> errors::Result<Vec<CardBrief>> {
+        self.0
+            .cards_for(OBJECT_NAME, value.request_name(self.0.lang)?)
+            .await
+    }
 }
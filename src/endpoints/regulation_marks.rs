@@ -0,0 +1,56 @@
+//! Get regulation marks list using [`RegulationMarkApi`].
+//!
+//! Regulation mark is the letter printed on cards, like D or E, used to define formats.
+
+use crate::errors;
+use crate::Tcgdex;
+
+const OBJECT_NAME: &str = "regulation-marks";
+
+/// Gives access to module functions.
+#[derive(Debug)]
+pub struct RegulationMarkApi<'a, C = Tcgdex>(pub(crate) &'a C);
+
+impl RegulationMarkApi<'_> {
+    /// Get all existing regulation marks.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{Tcgdex, Lang};
+    /// let tcgdex = Tcgdex::new();
+    /// println!("marks = {:?}", tcgdex.regulation_marks().fetch().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub fn fetch(&self) -> errors::Result<Vec<String>> {
+        let marks: Vec<String> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
+        Ok(marks)
+    }
+}
+
+#[cfg(feature = "async")]
+impl RegulationMarkApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing regulation marks.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("marks = {:?}", tcgdex.regulation_marks().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<String>> {
+        let marks: Vec<String> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(marks)
+    }
+}
@@ -1,19 +1,21 @@
 //! Get retreat costs list using [`RetreatApi`].
 
-use crate::query::URL_BASE;
+use crate::endpoints::cards::CardBrief;
+use crate::errors;
+use crate::Tcgdex;
 
 const OBJECT_NAME: &str = "retreats";
 
 /// Gives access to module functions.
 #[derive(Debug)]
-pub struct RetreatApi<'a>(pub(crate) &'a reqwest::blocking::Client, pub(crate) String);
+pub struct RetreatApi<'a, C = Tcgdex>(pub(crate) &'a C);
 
 impl RetreatApi<'_> {
     /// Get all existing Pokémon retreat costs.
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use tcgdex_api::{Tcgdex, Lang};
     /// let tcgdex = Tcgdex::new();
     /// println!("retreats = {:?}", tcgdex.retreats().fetch().unwrap());
@@ -22,12 +24,69 @@ impl RetreatApi<'_> {
     /// # Errors
     ///
     /// If TCGDEX API is updated with URL or JSON format modifications.
-    pub fn fetch(&self) -> reqwest::Result<Vec<u8>> {
-        let client: &reqwest::blocking::Client = self.0;
-        let retreats: Vec<u8> = client
-            .get(format!("{}{}/{OBJECT_NAME}", URL_BASE, self.1))
-            .send()?
-            .json()?;
+    pub fn fetch(&self) -> errors::Result<Vec<u8>> {
+        let retreats: Vec<u8> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
         Ok(retreats)
     }
+
+    /// Get the cards of Pokémon with `retreat` retreat cost.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// println!("cards = {:?}", tcgdex.retreats().cards_for(2).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, or if TCGDEX API is updated with URL or JSON format modifications.
+    pub fn cards_for(&self, retreat: u8) -> errors::Result<Vec<CardBrief>> {
+        self.0.cards_for(OBJECT_NAME, &retreat.to_string())
+    }
+}
+
+#[cfg(feature = "async")]
+impl RetreatApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing Pokémon retreat costs.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("retreats = {:?}", tcgdex.retreats().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<u8>> {
+        let retreats: Vec<u8> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(retreats)
+    }
+
+    /// Get the cards of Pokémon with `retreat` retreat cost.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("cards = {:?}", tcgdex.retreats().cards_for(2).await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, or if TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn cards_for(&self, retreat: u8) -> errors::Result<Vec<CardBrief>> {
+        self.0.cards_for(OBJECT_NAME, &retreat.to_string()).await
+    }
 }
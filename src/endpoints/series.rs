@@ -5,14 +5,19 @@
 use crate::endpoints::sets::SetBrief;
 use crate::errors;
 use crate::is_empty::IsEmpty;
-use serde::Deserialize;
+use crate::lenient::Lenient;
+use crate::pagination::Paginator;
+use crate::{Lang, Tcgdex};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 
-use crate::query::{Query, Response, URL_BASE};
+use crate::query::Query;
 
 const OBJECT_NAME: &str = "series";
 
 /// Brief information about the serie.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
 #[serde(default)]
 pub struct SerieBrief {
     /// Unique identifier for the object.
@@ -22,12 +27,12 @@ pub struct SerieBrief {
     pub name: String,
 
     /// The url to the logo image.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub logo: String,
 }
 
 /// Information about the serie.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
 pub struct Serie {
     /// Unique identifier for the object.
     pub id: String,
@@ -36,11 +41,15 @@ pub struct Serie {
     pub name: String,
 
     /// The url to the logo image.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub logo: String,
 
     /// The list of sets linked to this serie.
     pub sets: Vec<SetBrief>,
+
+    /// Fields unknown to this library, as returned by the API.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 impl IsEmpty for Serie {
@@ -57,9 +66,9 @@ impl IsEmpty for Vec<SerieBrief> {
 
 /// Gives access to module functions.
 #[derive(Debug)]
-pub struct SerieApi<'a>(pub(crate) &'a reqwest::blocking::Client, pub(crate) String);
+pub struct SerieApi<'a, C = Tcgdex>(pub(crate) &'a C);
 
-impl SerieApi<'_> {
+impl<'t> SerieApi<'t> {
     /// Get series.
     ///
     /// # Argument
@@ -68,7 +77,7 @@ impl SerieApi<'_> {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use tcgdex_api::{Tcgdex, Lang};
     /// use tcgdex_api::endpoints::series::SerieBrief;
     /// let tcgdex = Tcgdex::new();
@@ -78,31 +87,197 @@ impl SerieApi<'_> {
     ///
     /// # Errors
     ///
-    /// If TCGDEX API is updated with URL or JSON format modifications.
+    /// If TCGDEX API is updated with URL or JSON format modifications,
+    /// or if the query is invalid (see [`Query::validate`]).
     pub fn fetch<T>(&self, query: Option<&Query>) -> errors::Result<T>
     where
         T: for<'a> Deserialize<'a> + IsEmpty,
     {
-        let client: &reqwest::blocking::Client = self.0;
-        let mut url_query = String::new();
-        let mut separator = String::from("/");
-
-        // if query is used.
-        if let Some(f) = query {
-            url_query = f.to_string();
+        if let Some(query) = query {
+            query.validate()?;
         }
+        let url = self.0.url(OBJECT_NAME, query);
+        self.0.get(&url).and_then(errors::non_empty)
+    }
+
+    /// Get a lazy iterator over all series, requesting pages of `items_per_page` series.
+    ///
+    /// # Arguments
+    ///
+    /// `query` - A [`Query`] used to filter or sort series. Its pagination is replaced.
+    /// `items_per_page` - Number of series requested at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// for item in tcgdex.series().paginate(None, 100) {
+    ///     println!("series = {:?}", item.unwrap());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn paginate(
+        &self,
+        query: Option<&Query>,
+        items_per_page: u16,
+    ) -> Paginator<'t, SerieBrief> {
+        Paginator::new(self.0, OBJECT_NAME, query, items_per_page)
+    }
 
-        // if query is used to filtering, sorting or pagination.
-        if url_query.contains('&') || url_query.contains('=') {
-            separator = String::from('?');
+    /// Get the serie `id` in each langage of `langs`.
+    ///
+    /// Requests are sent concurrently. Langages in which the serie does not exist are not in the returned map.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{Tcgdex, Lang};
+    /// let tcgdex = Tcgdex::new();
+    /// let series = tcgdex.series().fetch_langs("swsh", &[Lang::EN, Lang::FR]).unwrap();
+    /// println!("serie names = {:?}", series.values().map(|v| &v.name).collect::<Vec<_>>());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If a request fails for another reason than a missing serie.
+    pub fn fetch_langs(&self, id: &str, langs: &[Lang]) -> errors::Result<HashMap<Lang, Serie>> {
+        self.0.fetch_langs(OBJECT_NAME, id, langs)
+    }
+
+    /// Get the serie `id`, decoded leniently: see [`lenient`](crate::lenient) module.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// let serie = tcgdex.series().fetch_lenient("swsh").unwrap();
+    /// println!("warnings = {:?}", serie.warnings);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the request fails, or if the response is not a serie.
+    pub fn fetch_lenient(&self, id: &str) -> errors::Result<Lenient<Serie>> {
+        self.0.fetch_lenient(OBJECT_NAME, id)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'t> SerieApi<'t, crate::AsyncTcgdex> {
+    /// Get series.
+    ///
+    /// # Argument
+    ///
+    /// `query` - A [`Query`] used to get series. If None, returns all series.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// use tcgdex_api::endpoints::series::SerieBrief;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let series: Vec<SerieBrief> = tcgdex.series().fetch(None).await.unwrap();
+    /// println!("series = {:?}", series);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications,
+    /// or if the query is invalid (see [`Query::validate`]).
+    pub async fn fetch<T>(&self, query: Option<&Query>) -> errors::Result<T>
+    where
+        T: for<'a> Deserialize<'a> + IsEmpty,
+    {
+        if let Some(query) = query {
+            query.validate()?;
         }
+        let url = self.0.url(OBJECT_NAME, query);
+        self.0.get(&url).await.and_then(errors::non_empty)
+    }
+
+    /// Get a lazy stream over all series, requesting pages of `items_per_page` series.
+    ///
+    /// # Arguments
+    ///
+    /// `query` - A [`Query`] used to filter or sort series. Its pagination is replaced.
+    /// `items_per_page` - Number of series requested at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// use futures_util::StreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let mut series = std::pin::pin!(tcgdex.series().paginate(None, 100));
+    /// while let Some(item) = series.next().await {
+    ///     println!("series = {:?}", item.unwrap());
+    /// }
+    /// # }
+    /// ```
+    pub fn paginate(
+        &self,
+        query: Option<&Query>,
+        items_per_page: u16,
+    ) -> impl futures_util::Stream<Item = errors::Result<SerieBrief>> + 't {
+        crate::pagination::stream(self.0, OBJECT_NAME, query, items_per_page)
+    }
 
-        let url = format!(
-            "{}{}/{OBJECT_NAME}{}{url_query}",
-            URL_BASE, self.1, separator
-        );
+    /// Get the serie `id` in each langage of `langs`.
+    ///
+    /// Requests are sent concurrently. Langages in which the serie does not exist are not in the returned map.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{AsyncTcgdex, Lang};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let series = tcgdex
+    ///     .series()
+    ///     .fetch_langs("swsh", &[Lang::EN, Lang::FR])
+    ///     .await
+    ///     .unwrap();
+    /// println!("serie names = {:?}", series.values().map(|v| &v.name).collect::<Vec<_>>());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If a request fails for another reason than a missing serie.
+    pub async fn fetch_langs(
+        &self,
+        id: &str,
+        langs: &[Lang],
+    ) -> errors::Result<HashMap<Lang, Serie>> {
+        self.0.fetch_langs(OBJECT_NAME, id, langs).await
+    }
 
-        let response: Response<T> = client.get(url).send()?.json()?;
-        errors::set_error(response)
+    /// Get the serie `id`, decoded leniently: see [`lenient`](crate::lenient) module.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let serie = tcgdex.series().fetch_lenient("swsh").await.unwrap();
+    /// println!("warnings = {:?}", serie.warnings);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the request fails, or if the response is not a serie.
+    pub async fn fetch_lenient(&self, id: &str) -> errors::Result<Lenient<Serie>> {
+        self.0.fetch_lenient(OBJECT_NAME, id).await
     }
 }
@@ -2,18 +2,24 @@
 //!
 //! Set is a group of cards.
 
-use crate::endpoints::cards::CardBrief;
+use crate::endpoints::cards::{Card, CardBrief};
 use crate::endpoints::series::SerieBrief;
 use crate::errors;
+use crate::filter::{self, Field, Filter};
 use crate::is_empty::IsEmpty;
-use serde::Deserialize;
+use crate::lenient::Lenient;
+use crate::pagination::Paginator;
+use crate::{Lang, Tcgdex};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 
-use crate::query::{Query, Response, URL_BASE};
+use crate::query::Query;
 
 const OBJECT_NAME: &str = "sets";
 
 /// Contain brief information about the number of cards in the set
-#[derive(Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy)]
 pub struct CardCountBrief {
     /// The total amount of cards in set including hidden.
     pub total: u16,
@@ -23,7 +29,7 @@ pub struct CardCountBrief {
 }
 
 /// Contain information about the number of cards in the set.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 pub struct CardCount {
     /// The total amount of cards in set including hidden.
@@ -43,7 +49,7 @@ pub struct CardCount {
 }
 
 /// Set usability in competitions.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy)]
 pub struct Legal {
     /// Ability to use this set in standard competitions.
     pub standard: bool,
@@ -53,7 +59,7 @@ pub struct Legal {
 }
 
 /// Contains a brief set representation.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct SetBrief {
     /// Unique identifier for the object.
@@ -63,11 +69,11 @@ pub struct SetBrief {
     pub name: String,
 
     /// The url to the logo image.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub logo: String,
 
     /// The url to the symbol image.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub symbol: String,
 
     /// Number of cards in the set.
@@ -75,7 +81,7 @@ pub struct SetBrief {
 }
 
 /// Contains set Data.
-#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Set {
     /// Unique identifier for the object.
@@ -85,11 +91,11 @@ pub struct Set {
     pub name: String,
 
     /// The url to the logo image.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub logo: String,
 
     /// The url to the symbol image.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub symbol: String,
 
     /// Number of cards in the set.
@@ -99,7 +105,7 @@ pub struct Set {
     pub serie: SerieBrief,
 
     /// Pokémon TCG Online Set code.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub tcg_online: String,
 
     /// The set release date in the form yyyy-mm-dd.
@@ -110,6 +116,10 @@ pub struct Set {
 
     /// List of cards in this set.
     pub cards: Vec<CardBrief>,
+
+    /// Fields unknown to this library, as returned by the API.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 impl IsEmpty for Set {
@@ -126,9 +136,9 @@ impl IsEmpty for Vec<SetBrief> {
 
 /// Gives access to module functions.
 #[derive(Debug)]
-pub struct SetApi<'a>(pub(crate) &'a reqwest::blocking::Client, pub(crate) String);
+pub struct SetApi<'a, C = Tcgdex>(pub(crate) &'a C);
 
-impl SetApi<'_> {
+impl<'t> SetApi<'t> {
     /// Get sets.
     ///
     /// # Argument
@@ -137,7 +147,7 @@ impl SetApi<'_> {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use tcgdex_api::{Tcgdex, Lang};
     /// use tcgdex_api::endpoints::sets::SetBrief;
     /// let tcgdex = Tcgdex::new();
@@ -147,31 +157,386 @@ impl SetApi<'_> {
     ///
     /// # Errors
     ///
-    /// If TCGDEX API is updated with URL or JSON format modifications.
+    /// If TCGDEX API is updated with URL or JSON format modifications,
+    /// or if the query is invalid (see [`Query::validate`]).
     pub fn fetch<T>(&self, query: Option<&Query>) -> errors::Result<T>
     where
         T: for<'a> Deserialize<'a> + IsEmpty,
     {
-        let client: &reqwest::blocking::Client = self.0;
-        let mut url_query = String::new();
-        let mut separator = String::from("/");
-
-        // if query is used.
-        if let Some(f) = query {
-            url_query = f.to_string();
+        if let Some(query) = query {
+            query.validate()?;
         }
+        let url = self.0.url(OBJECT_NAME, query);
+        self.0.get(&url).and_then(errors::non_empty)
+    }
 
-        // if query is used to filtering, sorting or pagination.
-        if url_query.contains('&') || url_query.contains('=') {
-            separator = String::from('?');
+    /// Get a lazy iterator over all sets, requesting pages of `items_per_page` sets.
+    ///
+    /// # Arguments
+    ///
+    /// `query` - A [`Query`] used to filter or sort sets. Its pagination is replaced.
+    /// `items_per_page` - Number of sets requested at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// for item in tcgdex.sets().paginate(None, 100) {
+    ///     println!("sets = {:?}", item.unwrap());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn paginate(&self, query: Option<&Query>, items_per_page: u16) -> Paginator<'t, SetBrief> {
+        Paginator::new(self.0, OBJECT_NAME, query, items_per_page)
+    }
+
+    /// Get the set `id` in each langage of `langs`.
+    ///
+    /// Requests are sent concurrently. Langages in which the set does not exist are not in the returned map.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{Tcgdex, Lang};
+    /// let tcgdex = Tcgdex::new();
+    /// let sets = tcgdex.sets().fetch_langs("swsh3", &[Lang::EN, Lang::FR]).unwrap();
+    /// println!("set names = {:?}", sets.values().map(|v| &v.name).collect::<Vec<_>>());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If a request fails for another reason than a missing set.
+    pub fn fetch_langs(&self, id: &str, langs: &[Lang]) -> errors::Result<HashMap<Lang, Set>> {
+        self.0.fetch_langs(OBJECT_NAME, id, langs)
+    }
+
+    /// Get the set `id`, decoded leniently: see [`lenient`](crate::lenient) module.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// let set = tcgdex.sets().fetch_lenient("swsh3").unwrap();
+    /// println!("warnings = {:?}", set.warnings);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the request fails, or if the response is not a set.
+    pub fn fetch_lenient(&self, id: &str) -> errors::Result<Lenient<Set>> {
+        self.0.fetch_lenient(OBJECT_NAME, id)
+    }
+
+    /// Get the sets `ids`, requesting several sets at once.
+    ///
+    /// Duplicated ids are requested once. Results are in order of first occurrence of their id,
+    /// each with its own result: a missing set does not prevent getting the others.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// for (id, set) in tcgdex.sets().fetch_many(&["swsh3", "swsh1"]) {
+    ///     println!("{id} = {:?}", set);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn fetch_many(&self, ids: &[impl AsRef<str>]) -> Vec<(String, errors::Result<Set>)> {
+        self.0.fetch_many(OBJECT_NAME, ids)
+    }
+
+    /// Get the card `local_id` of the set `set`, given by id (`swsh3`) or by full name (`Darkness Ablaze`).
+    ///
+    /// `local_id` is the number printed on the card, like `136`, `TG05` or `SV107`. It is also found
+    /// with or without its leading zeros (`7` for `007`), in any case, and followed by the set total (`136/189`).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// let tcgdex = Tcgdex::new();
+    /// let card = tcgdex.sets().fetch_card("Darkness Ablaze", "136").unwrap();
+    /// println!("card = {:?}", card);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the set or the card does not exist, or if a request fails.
+    pub fn fetch_card(&self, set: &str, local_id: &str) -> errors::Result<Card> {
+        let local_id = printed_id(local_id);
+        let url = card_url(&self.0.url(OBJECT_NAME, None), set, local_id);
+        let not_found = match self.0.get(&url).and_then(errors::non_empty) {
+            Err(error) if error.is_not_found() => error,
+            result => return result,
+        };
+        // the set is given by name, or the local id is written differently.
+        let found: Set = match self.fetch(Some(&Query::new().with_id(set))) {
+            Err(error) if error.is_not_found() => {
+                let sets = self.fetch(Some(&name_query(set)));
+                let Some(id) = named_set(sets, set)? else {
+                    return Err(not_found);
+                };
+                self.fetch(Some(&Query::new().with_id(&id)))?
+            }
+            result => result?,
+        };
+        let Some(card) = find_card(&found, local_id) else {
+            return Err(not_found);
+        };
+        let url = card_url(&self.0.url(OBJECT_NAME, None), &found.id, &card.local_id);
+        self.0.get(&url).and_then(errors::non_empty)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'t> SetApi<'t, crate::AsyncTcgdex> {
+    /// Get sets.
+    ///
+    /// # Argument
+    ///
+    /// `query` - A [`Query`] used to get sets. If None, returns all sets.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// use tcgdex_api::endpoints::sets::SetBrief;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let sets: Vec<SetBrief> = tcgdex.sets().fetch(None).await.unwrap();
+    /// println!("sets = {:?}", sets);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications,
+    /// or if the query is invalid (see [`Query::validate`]).
+    pub async fn fetch<T>(&self, query: Option<&Query>) -> errors::Result<T>
+    where
+        T: for<'a> Deserialize<'a> + IsEmpty,
+    {
+        if let Some(query) = query {
+            query.validate()?;
         }
+        let url = self.0.url(OBJECT_NAME, query);
+        self.0.get(&url).await.and_then(errors::non_empty)
+    }
 
-        let url = format!(
-            "{}{}/{OBJECT_NAME}{}{url_query}",
-            URL_BASE, self.1, separator
-        );
+    /// Get a lazy stream over all sets, requesting pages of `items_per_page` sets.
+    ///
+    /// # Arguments
+    ///
+    /// `query` - A [`Query`] used to filter or sort sets. Its pagination is replaced.
+    /// `items_per_page` - Number of sets requested at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// use futures_util::StreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let mut sets = std::pin::pin!(tcgdex.sets().paginate(None, 100));
+    /// while let Some(item) = sets.next().await {
+    ///     println!("sets = {:?}", item.unwrap());
+    /// }
+    /// # }
+    /// ```
+    pub fn paginate(
+        &self,
+        query: Option<&Query>,
+        items_per_page: u16,
+    ) -> impl futures_util::Stream<Item = errors::Result<SetBrief>> + 't {
+        crate::pagination::stream(self.0, OBJECT_NAME, query, items_per_page)
+    }
+
+    /// Get the set `id` in each langage of `langs`.
+    ///
+    /// Requests are sent concurrently. Langages in which the set does not exist are not in the returned map.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{AsyncTcgdex, Lang};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let sets = tcgdex
+    ///     .sets()
+    ///     .fetch_langs("swsh3", &[Lang::EN, Lang::FR])
+    ///     .await
+    ///     .unwrap();
+    /// println!("set names = {:?}", sets.values().map(|v| &v.name).collect::<Vec<_>>());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If a request fails for another reason than a missing set.
+    pub async fn fetch_langs(
+        &self,
+        id: &str,
+        langs: &[Lang],
+    ) -> errors::Result<HashMap<Lang, Set>> {
+        self.0.fetch_langs(OBJECT_NAME, id, langs).await
+    }
+
+    /// Get the set `id`, decoded leniently: see [`lenient`](crate::lenient) module.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let set = tcgdex.sets().fetch_lenient("swsh3").await.unwrap();
+    /// println!("warnings = {:?}", set.warnings);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the request fails, or if the response is not a set.
+    pub async fn fetch_lenient(&self, id: &str) -> errors::Result<Lenient<Set>> {
+        self.0.fetch_lenient(OBJECT_NAME, id).await
+    }
+
+    /// Get the sets `ids`, requesting several sets at once.
+    ///
+    /// Duplicated ids are requested once. Results are in order of first occurrence of their id,
+    /// each with its own result: a missing set does not prevent getting the others.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// for (id, set) in tcgdex.sets().fetch_many(&["swsh3", "swsh1"]).await {
+    ///     println!("{id} = {:?}", set);
+    /// }
+    /// # }
+    /// ```
+    pub async fn fetch_many(&self, ids: &[impl AsRef<str>]) -> Vec<(String, errors::Result<Set>)> {
+        self.0.fetch_many(OBJECT_NAME, ids).await
+    }
+
+    /// Get the card `local_id` of the set `set`, given by id (`swsh3`) or by full name (`Darkness Ablaze`).
+    ///
+    /// `local_id` is the number printed on the card, like `136`, `TG05` or `SV107`. It is also found
+    /// with or without its leading zeros (`7` for `007`), in any case, and followed by the set total (`136/189`).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// let card = tcgdex.sets().fetch_card("Darkness Ablaze", "136").await.unwrap();
+    /// println!("card = {:?}", card);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the set or the card does not exist, or if a request fails.
+    pub async fn fetch_card(&self, set: &str, local_id: &str) -> errors::Result<Card> {
+        let local_id = printed_id(local_id);
+        let url = card_url(&self.0.url(OBJECT_NAME, None), set, local_id);
+        let not_found = match self.0.get(&url).await.and_then(errors::non_empty) {
+            Err(error) if error.is_not_found() => error,
+            result => return result,
+        };
+        // the set is given by name, or the local id is written differently.
+        let found: Set = match self.fetch(Some(&Query::new().with_id(set))).await {
+            Err(error) if error.is_not_found() => {
+                let sets = self.fetch(Some(&name_query(set))).await;
+                let Some(id) = named_set(sets, set)? else {
+                    return Err(not_found);
+                };
+                self.fetch(Some(&Query::new().with_id(&id))).await?
+            }
+            result => result?,
+        };
+        let Some(card) = find_card(&found, local_id) else {
+            return Err(not_found);
+        };
+        let url = card_url(&self.0.url(OBJECT_NAME, None), &found.id, &card.local_id);
+        self.0.get(&url).await.and_then(errors::non_empty)
+    }
+}
+
+/// Get the URL of the card `local_id` of the set `set` from the sets endpoint URL.
+fn card_url(sets_url: &str, set: &str, local_id: &str) -> String {
+    format!(
+        "{sets_url}/{}/{}",
+        filter::percent_encode(set),
+        filter::percent_encode(local_id)
+    )
+}
+
+/// Get the local id printed on a card, without the set total of `136/189`.
+fn printed_id(local_id: &str) -> &str {
+    local_id.split('/').next().unwrap_or_default().trim()
+}
+
+/// Get the query of sets with a name like `name`.
+fn name_query(name: &str) -> Query {
+    Query::new().with_filter(Filter::field(Field::NAME).like(name))
+}
+
+/// Get the id of the set named `name` in any case in `sets`, the response to [`name_query`].
+///
+/// Sets whose name only contains `name` are not guessed.
+fn named_set(sets: errors::Result<Vec<SetBrief>>, name: &str) -> errors::Result<Option<String>> {
+    let sets = match sets {
+        Err(error) if error.is_not_found() => return Ok(None),
+        result => result?,
+    };
+    Ok(sets
+        .into_iter()
+        .find(|set| set.name.eq_ignore_ascii_case(name))
+        .map(|set| set.id))
+}
+
+/// Find the card of `set` with `local_id`, ignoring case and leading zeros (`tg5` for `TG05`).
+fn find_card<'s>(set: &'s Set, local_id: &str) -> Option<&'s CardBrief> {
+    let local_id = normalize(local_id);
+    set.cards
+        .iter()
+        .find(|card| normalize(&card.local_id) == local_id)
+}
+
+/// Get `local_id` in upper case, without leading zeros in its numbers.
+fn normalize(local_id: &str) -> String {
+    let mut normalized = String::with_capacity(local_id.len());
+    let mut number = String::new();
+    for c in local_id.chars().map(|c| c.to_ascii_uppercase()) {
+        if c.is_ascii_digit() {
+            number.push(c);
+        } else {
+            push_number(&mut normalized, &mut number);
+            normalized.push(c);
+        }
+    }
+    push_number(&mut normalized, &mut number);
+    normalized
+}
 
-        let response: Response<T> = client.get(url).send()?.json()?;
-        errors::set_error(response)
+/// Move `number` to `normalized`, without its leading zeros.
+fn push_number(normalized: &mut String, number: &mut String) {
+    if !number.is_empty() {
+        let trimmed = number.trim_start_matches('0');
+        normalized.push_str(if trimmed.is_empty() { "0" } else { trimmed });
+        number.clear();
     }
 }
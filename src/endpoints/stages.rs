@@ -0,0 +1,57 @@
+//! Get Pokémon stages list using [`StageApi`].
+//!
+//! Stage is like basic or stage 1 for example.
+
+use crate::endpoints::cards::Stage;
+use crate::errors;
+use crate::Tcgdex;
+
+const OBJECT_NAME: &str = "stages";
+
+/// Gives access to module functions.
+#[derive(Debug)]
+pub struct StageApi<'a, C = Tcgdex>(pub(crate) &'a C);
+
+impl StageApi<'_> {
+    /// Get all existing Pokémon stages.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{Tcgdex, Lang};
+    /// let tcgdex = Tcgdex::new();
+    /// println!("stages = {:?}", tcgdex.stages().fetch().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub fn fetch(&self) -> errors::Result<Vec<Stage>> {
+        let stages: Vec<Stage> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
+        Ok(stages)
+    }
+}
+
+#[cfg(feature = "async")]
+impl StageApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing Pokémon stages.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("stages = {:?}", tcgdex.stages().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<Stage>> {
+        let stages: Vec<Stage> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(stages)
+    }
+}
@@ -0,0 +1,56 @@
+//! Get card suffixes list using [`SuffixApi`].
+//!
+//! Suffix is like EX or V for example.
+
+use crate::errors;
+use crate::Tcgdex;
+
+const OBJECT_NAME: &str = "suffixes";
+
+/// Gives access to module functions.
+#[derive(Debug)]
+pub struct SuffixApi<'a, C = Tcgdex>(pub(crate) &'a C);
+
+impl SuffixApi<'_> {
+    /// Get all existing card suffixes.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{Tcgdex, Lang};
+    /// let tcgdex = Tcgdex::new();
+    /// println!("suffixes = {:?}", tcgdex.suffixes().fetch().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub fn fetch(&self) -> errors::Result<Vec<String>> {
+        let suffixes: Vec<String> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
+        Ok(suffixes)
+    }
+}
+
+#[cfg(feature = "async")]
+impl SuffixApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing card suffixes.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("suffixes = {:?}", tcgdex.suffixes().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<String>> {
+        let suffixes: Vec<String> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(suffixes)
+    }
+}
@@ -0,0 +1,57 @@
+//! Get trainer types list using [`TrainerTypeApi`].
+//!
+//! Trainer type is like item or supporter for example.
+
+use crate::endpoints::cards::TrainerType;
+use crate::errors;
+use crate::Tcgdex;
+
+const OBJECT_NAME: &str = "trainer-types";
+
+/// Gives access to module functions.
+#[derive(Debug)]
+pub struct TrainerTypeApi<'a, C = Tcgdex>(pub(crate) &'a C);
+
+impl TrainerTypeApi<'_> {
+    /// Get all existing trainer card types.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{Tcgdex, Lang};
+    /// let tcgdex = Tcgdex::new();
+    /// println!("trainer_types = {:?}", tcgdex.trainer_types().fetch().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub fn fetch(&self) -> errors::Result<Vec<TrainerType>> {
+        let trainer_types: Vec<TrainerType> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
+        Ok(trainer_types)
+    }
+}
+
+#[cfg(feature = "async")]
+impl TrainerTypeApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing trainer card types.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("trainer_types = {:?}", tcgdex.trainer_types().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<TrainerType>> {
+        let trainer_types: Vec<TrainerType> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(trainer_types)
+    }
+}
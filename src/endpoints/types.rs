@@ -2,20 +2,114 @@
 //!
 //! Types is like fire or psychic for example.
 
-use crate::query::URL_BASE;
+use crate::endpoints::cards::CardBrief;
+use crate::errors;
+use crate::string_enum::{string_enum, RequestName};
+use crate::Tcgdex;
 
 const OBJECT_NAME: &str = "types";
 
+string_enum! {
+    /// A Pokémon or energy type, as used in card types and attack costs.
+    pub enum PokemonType {
+        /// Colorless type.
+        Colorless => "Colorless",
+            FR: "Incolore",
+            DE: "Farblos",
+            IT: "Incolore",
+            PT: "Incolor",
+            ES: "Incoloro";
+
+        /// Darkness type.
+        Darkness => "Darkness",
+            FR: "Obscurité",
+            DE: "Finsternis",
+            IT: "Oscurità",
+            PT: "Escuridão",
+            ES: "Oscura";
+
+        /// Dragon type.
+        Dragon => "Dragon",
+            DE: "Drache",
+            IT: "Drago",
+            PT: "Dragão",
+            ES: "Dragón";
+
+        /// Fairy type.
+        Fairy => "Fairy",
+            FR: "Fée",
+            DE: "Fee",
+            IT: "Folletto",
+            PT: "Fada",
+            ES: "Hada";
+
+        /// Fighting type.
+        Fighting => "Fighting",
+            FR: "Combat",
+            DE: "Kampf",
+            IT: "Lotta",
+            PT: "Luta",
+            ES: "Lucha";
+
+        /// Fire type.
+        Fire => "Fire",
+            FR: "Feu",
+            DE: "Feuer",
+            IT: "Fuoco",
+            PT: "Fogo",
+            ES: "Fuego";
+
+        /// Grass type.
+        Grass => "Grass",
+            FR: "Plante",
+            DE: "Pflanze",
+            IT: "Erba",
+            PT: "Grama",
+            ES: "Planta";
+
+        /// Lightning type.
+        Lightning => "Lightning",
+            FR: "Électrique",
+            DE: "Elektro",
+            IT: "Lampo",
+            PT: "Elétrico",
+            ES: "Rayo";
+
+        /// Metal type.
+        Metal => "Metal",
+            FR: "Métal",
+            DE: "Metall",
+            IT: "Metallo",
+            ES: "Metálica";
+
+        /// Psychic type.
+        Psychic => "Psychic",
+            FR: "Psy",
+            DE: "Psycho",
+            IT: "Psico",
+            PT: "Psíquico",
+            ES: "Psíquica";
+
+        /// Water type.
+        Water => "Water",
+            FR: "Eau",
+            DE: "Wasser",
+            IT: "Acqua",
+            PT: "Água",
+            ES: "Agua";
+    }
+}
+
 /// Gives access to module functions.
 #[derive(Debug)]
-pub struct TypeApi<'a>(pub(crate) &'a reqwest::blocking::Client, pub(crate) String);
+pub struct TypeApi<'a, C = Tcgdex>(pub(crate) &'a C);
 
 impl TypeApi<'_> {
     /// Get all existing Pokémon types.
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # use tcgdex_api::{Tcgdex, Lang};
     /// let tcgdex = Tcgdex::new();
     /// println!("types = {:?}", tcgdex.types().fetch().unwrap());
@@ -24,12 +118,82 @@ impl TypeApi<'_> {
     /// # Errors
     ///
     /// If TCGDEX API is updated with URL or JSON format modifications.
-    pub fn fetch(&self) -> reqwest::Result<Vec<String>> {
-        let client: &reqwest::blocking::Client = self.0;
-        let types: Vec<String> = client
-            .get(format!("{}{}/{OBJECT_NAME}", URL_BASE, self.1))
-            .send()?
-            .json()?;
+    pub fn fetch(&self) -> errors::Result<Vec<PokemonType>> {
+        let types: Vec<PokemonType> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
         Ok(types)
     }
+
+    /// Get the cards of `value` type.
+    ///
+    /// The type is requested by its name in the client langage.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::Tcgdex;
+    /// use tcgdex_api::PokemonType;
+    /// let tcgdex = Tcgdex::new();
+    /// println!("cards = {:?}", tcgdex.types().cards_for(&PokemonType::Fire).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, if its names in the client langage are not known
+    /// (see [`Lang::has_value_names`](crate::Lang::has_value_names)),
+    /// or if TCGDEX API is updated with URL or JSON format modifications.
+    pub fn cards_for(&self, value: &PokemonType) -> errors::Result<Vec<CardBrief>> {
+        self.0
+            .cards_for(OBJECT_NAME, value.request_name(self.0.lang)?)
+    }
+}
+
+#[cfg(feature = "async")]
+impl TypeApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing Pokémon types.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("types = {:?}", tcgdex.types().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<PokemonType>> {
+        let types: Vec<PokemonType> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(types)
+    }
+
+    /// Get the cards of `value` type.
+    ///
+    /// The type is requested by its name in the client langage.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// use tcgdex_api::PokemonType;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("cards = {:?}", tcgdex.types().cards_for(&PokemonType::Fire).await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value is unknown, if its names in the client langage are not known
+    /// (see [`Lang::has_value_names`](crate::Lang::has_value_names)),
+    /// or if TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn cards_for(&self, value: &PokemonType) -> errors::Result<Vec<CardBrief>> {
+        self.0
+            .cards_for(OBJECT_NAME, value.request_name(self.0.lang)?)
+            .await
+    }
 }
@@ -0,0 +1,56 @@
+//! Get card variants list using [`VariantApi`].
+//!
+//! Variant is like normal, reverse or holo for example.
+
+use crate::errors;
+use crate::Tcgdex;
+
+const OBJECT_NAME: &str = "variants";
+
+/// Gives access to module functions.
+#[derive(Debug)]
+pub struct VariantApi<'a, C = Tcgdex>(pub(crate) &'a C);
+
+impl VariantApi<'_> {
+    /// Get all existing card variants.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::{Tcgdex, Lang};
+    /// let tcgdex = Tcgdex::new();
+    /// println!("variants = {:?}", tcgdex.variants().fetch().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub fn fetch(&self) -> errors::Result<Vec<String>> {
+        let variants: Vec<String> = self.0.get(&self.0.url(OBJECT_NAME, None))?;
+        Ok(variants)
+    }
+}
+
+#[cfg(feature = "async")]
+impl VariantApi<'_, crate::AsyncTcgdex> {
+    /// Get all existing card variants.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::AsyncTcgdex;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let tcgdex = AsyncTcgdex::new();
+    /// println!("variants = {:?}", tcgdex.variants().fetch().await.unwrap());
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If TCGDEX API is updated with URL or JSON format modifications.
+    pub async fn fetch(&self) -> errors::Result<Vec<String>> {
+        let variants: Vec<String> = self.0.get(&self.0.url(OBJECT_NAME, None)).await?;
+        Ok(variants)
+    }
+}
@@ -1,15 +1,21 @@
 //! Allow to get error information.
 
 use crate::is_empty::IsEmpty;
-use crate::query::Response;
-use serde::Deserialize;
+use crate::retry;
+use crate::Lang;
+use reqwest::header::{HeaderValue, RETRY_AFTER};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
 use thiserror::Error;
 
 /// A `Result` alias where the `Err` case is [`Error`].
 pub type Result<T> = std::result::Result<T, ApiError>;
 
 /// Error returned by TCGDEX API in some cases of bad request.
-#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct TcgdexError {
     /// A URL that identifies the problem type.
     #[serde(rename = "type")]
@@ -37,19 +43,107 @@ pub struct TcgdexError {
 }
 
 /// The errors that may occur.
+///
+/// Errors of requests carry the requested URL.
 #[derive(Debug, Error)]
 pub enum ApiError {
-    /// Error from reqwest
+    /// Error from reqwest, like a connection failure.
     #[error("Reqwest error : {}", .0)]
     Reqwest(#[from] reqwest::Error),
 
-    /// Error from TCGDEX API.
-    #[error("Tcgdex error : {}", .0.title)]
-    TcgdexApi(TcgdexError),
+    /// The request did not complete in time.
+    #[error("Timeout : {url}")]
+    Timeout {
+        /// The requested URL.
+        url: String,
+    },
+
+    /// The requested object does not exist (HTTP 404).
+    #[error("Not found : {url}")]
+    NotFound {
+        /// The requested URL.
+        url: String,
+
+        /// The error sent by TCGDEX API, if any.
+        error: Option<Box<TcgdexError>>,
+    },
+
+    /// Too many requests were sent (HTTP 429).
+    #[error("Rate limited : {url}")]
+    RateLimited {
+        /// The requested URL.
+        url: String,
+
+        /// The delay to wait before sending a new request, from the `Retry-After` header.
+        retry_after: Option<Duration>,
+    },
+
+    /// The server failed to answer (HTTP 5xx).
+    #[error("Server error {status} : {url}")]
+    Server {
+        /// The requested URL.
+        url: String,
+
+        /// The HTTP status code.
+        status: u16,
+
+        /// The error sent by TCGDEX API, if any.
+        error: Option<Box<TcgdexError>>,
+    },
+
+    /// Other error from TCGDEX API, like a bad request.
+    #[error("Tcgdex error : {}", .error.title)]
+    TcgdexApi {
+        /// The requested URL.
+        url: String,
+
+        /// The error sent by TCGDEX API.
+        error: Box<TcgdexError>,
+    },
+
+    /// Other HTTP error, without details from TCGDEX API.
+    #[error("HTTP error {status} : {url}")]
+    Http {
+        /// The requested URL.
+        url: String,
+
+        /// The HTTP status code.
+        status: u16,
+    },
+
+    /// The response does not match the expected JSON format.
+    #[error("Deserialization error at {path} of {url} : {source} (near `{snippet}`)")]
+    Deserialization {
+        /// The requested URL.
+        url: String,
+
+        /// The path of the faulty value in the response, like `attacks[0].damage`.
+        path: String,
+
+        /// The response text around the faulty value.
+        snippet: String,
+
+        /// The error from `serde_json`.
+        source: serde_json::Error,
+    },
 
     /// Response is empty.
     #[error("Response is empty")]
     EmptyResponse,
+
+    /// The value cannot be requested by name in the client langage, whose names are not known.
+    #[error("No {lang} name known for {value}")]
+    UnknownValueName {
+        /// The english name of the value.
+        value: String,
+
+        /// The client langage.
+        lang: Lang,
+    },
+
+    /// Query parameters cannot be used together.
+    #[error("Invalid query : {}", .0)]
+    InvalidQuery(#[from] QueryError),
 }
 
 impl ApiError {
@@ -59,10 +153,10 @@ impl ApiError {
         matches!(self, Self::Reqwest(_))
     }
 
-    /// Returns true if the error is from the TCGDEX API.
+    /// Returns true if the error comes with an error sent by the TCGDEX API.
     #[must_use]
     pub fn is_tcgdexapi(&self) -> bool {
-        matches!(self, Self::TcgdexApi(_))
+        self.tcgdex_error().is_some()
     }
 
     /// Returns true if the error is from an empty response.
@@ -71,39 +165,367 @@ impl ApiError {
         matches!(self, Self::EmptyResponse)
     }
 
+    /// Returns true if the error is from an invalid query.
+    #[must_use]
+    pub fn is_invalid_query(&self) -> bool {
+        matches!(self, Self::InvalidQuery(_))
+    }
+
+    /// Returns true if the requested object does not exist: "not found" error or empty response.
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound { .. } | Self::EmptyResponse)
+    }
+
+    /// Returns true if the request did not complete in time.
+    #[must_use]
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout { .. })
+    }
+
+    /// Returns true if too many requests were sent.
+    #[must_use]
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, Self::RateLimited { .. })
+    }
+
+    /// Returns true if the server failed to answer.
+    #[must_use]
+    pub fn is_server_error(&self) -> bool {
+        matches!(self, Self::Server { .. })
+    }
+
+    /// Returns true if the response does not match the expected JSON format.
+    #[must_use]
+    pub fn is_deserialization(&self) -> bool {
+        matches!(self, Self::Deserialization { .. })
+    }
+
+    /// Returns the requested URL, or None if the error is not from a request.
+    #[must_use]
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Self::Reqwest(err) => err.url().map(reqwest::Url::as_str),
+            Self::Timeout { url }
+            | Self::NotFound { url, .. }
+            | Self::RateLimited { url, .. }
+            | Self::Server { url, .. }
+            | Self::TcgdexApi { url, .. }
+            | Self::Http { url, .. }
+            | Self::Deserialization { url, .. } => Some(url),
+            Self::EmptyResponse | Self::InvalidQuery(_) | Self::UnknownValueName { .. } => None,
+        }
+    }
+
     /// Returns the TCGDEX error message or None if error is not from TCGDEX.
     #[must_use]
     pub fn get_tcgdex_error(self) -> Option<TcgdexError> {
         match self {
-            Self::TcgdexApi(err) => Some(err),
+            Self::NotFound { error, .. } | Self::Server { error, .. } => error.map(|e| *e),
+            Self::TcgdexApi { error, .. } => Some(*error),
+            _ => None,
+        }
+    }
+
+    /// Get the error sent by TCGDEX API, if any.
+    fn tcgdex_error(&self) -> Option<&TcgdexError> {
+        match self {
+            Self::NotFound { error, .. } | Self::Server { error, .. } => error.as_deref(),
+            Self::TcgdexApi { error, .. } => Some(error),
             _ => None,
         }
     }
+
+    /// Create the error of a failed request to `url`.
+    pub(crate) fn request(url: &str, error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            Self::Timeout {
+                url: url.to_string(),
+            }
+        } else {
+            Self::Reqwest(error)
+        }
+    }
+
+    /// Create the error of a response to `url` with HTTP `status`.
+    pub(crate) fn status(
+        url: &str,
+        status: StatusCode,
+        retry_after: Option<&HeaderValue>,
+        error: Option<TcgdexError>,
+    ) -> Self {
+        let url = url.to_string();
+        let error = error.map(Box::new);
+        match (status, error) {
+            (StatusCode::NOT_FOUND, error) => Self::NotFound { url, error },
+            (StatusCode::TOO_MANY_REQUESTS, _) => Self::RateLimited {
+                url,
+                retry_after: retry_after.and_then(retry::parse_retry_after),
+            },
+            (status, error) if status.is_server_error() => Self::Server {
+                url,
+                status: status.as_u16(),
+                error,
+            },
+            (_, Some(error)) => Self::TcgdexApi { url, error },
+            (status, None) => Self::Http {
+                url,
+                status: status.as_u16(),
+            },
+        }
+    }
 }
 
-// NOTE: reqwest error cannot be compared.
+/// Error returned when a langage tag does not match any [`Lang`].
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("Unknown langage : {}", .0)]
+pub struct UnknownLangError(pub String);
+
+/// Errors of [`Snapshot`](crate::snapshot::Snapshot) files.
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    /// The file cannot be read or written.
+    #[error("Snapshot file error : {}", .0)]
+    Io(#[from] std::io::Error),
+
+    /// The file content is not a snapshot.
+    #[error("Snapshot format error : {}", .0)]
+    Format(#[from] serde_json::Error),
+
+    /// The file was written with a format version not supported by this library.
+    #[error("Unsupported snapshot version : {}", .0)]
+    UnsupportedVersion(u32),
+}
+
+/// Errors of the [`cbor`](crate::cbor) export.
+#[cfg(feature = "cbor")]
+#[derive(Debug, Error)]
+pub enum CborError {
+    /// The value cannot be encoded, or written.
+    #[error("CBOR encode error : {}", .0)]
+    Encode(#[from] ciborium::ser::Error<std::io::Error>),
+
+    /// The value cannot be read, or decoded.
+    #[error("CBOR decode error : {}", .0)]
+    Decode(#[from] ciborium::de::Error<std::io::Error>),
+}
+
+/// Errors of an [`ImageMirror`](crate::mirror::ImageMirror).
+#[derive(Debug, Error)]
+pub enum MirrorError {
+    /// The mirror directory cannot be read or written.
+    #[error("Mirror file error : {}", .0)]
+    Io(#[from] std::io::Error),
+
+    /// The image cannot be downloaded.
+    #[error("Image download error : {}", .0)]
+    Api(#[from] ApiError),
+
+    /// The downloaded image does not have the size announced by the server.
+    #[error("Image {url} has {actual} bytes instead of {expected}")]
+    SizeMismatch {
+        /// The image URL.
+        url: String,
+
+        /// The size announced by the server, in bytes.
+        expected: u64,
+
+        /// The downloaded size, in bytes.
+        actual: u64,
+    },
+
+    /// The object has no image.
+    #[error("No image for {}", .0)]
+    MissingImage(String),
+}
+
+/// Conflicting parameters of a [`Query`](crate::Query).
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    /// Several different ids are set.
+    #[error("Several ids are set : {} and {}", .0, .1)]
+    ConflictingIds(String, String),
+
+    /// An id is combined with filters.
+    #[error("An id cannot be combined with filtering")]
+    IdWithFiltering,
+
+    /// An id is combined with sorting.
+    #[error("An id cannot be combined with sorting")]
+    IdWithSorting,
+
+    /// An id is combined with pagination.
+    #[error("An id cannot be combined with pagination")]
+    IdWithPagination,
+
+    /// A field is sorted in both orders.
+    #[error("Field {} is sorted in both orders", .0)]
+    ConflictingSortOrders(String),
+}
+
+// NOTE: reqwest and serde_json errors cannot be compared.
 impl PartialEq for ApiError {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::TcgdexApi(a), Self::TcgdexApi(b)) => a == b,
+            (Self::Timeout { url: a }, Self::Timeout { url: b }) => a == b,
+            (
+                Self::NotFound {
+                    url: a,
+                    error: error_a,
+                },
+                Self::NotFound {
+                    url: b,
+                    error: error_b,
+                },
+            ) => a == b && error_a == error_b,
+            (
+                Self::RateLimited {
+                    url: a,
+                    retry_after: after_a,
+                },
+                Self::RateLimited {
+                    url: b,
+                    retry_after: after_b,
+                },
+            ) => a == b && after_a == after_b,
+            (
+                Self::Server {
+                    url: a,
+                    status: status_a,
+                    error: error_a,
+                },
+                Self::Server {
+                    url: b,
+                    status: status_b,
+                    error: error_b,
+                },
+            ) => a == b && status_a == status_b && error_a == error_b,
+            (
+                Self::TcgdexApi {
+                    url: a,
+                    error: error_a,
+                },
+                Self::TcgdexApi {
+                    url: b,
+                    error: error_b,
+                },
+            ) => a == b && error_a == error_b,
+            (
+                Self::Http {
+                    url: a,
+                    status: status_a,
+                },
+                Self::Http {
+                    url: b,
+                    status: status_b,
+                },
+            ) => a == b && status_a == status_b,
+            (
+                Self::Deserialization {
+                    url: a,
+                    path: path_a,
+                    ..
+                },
+                Self::Deserialization {
+                    url: b,
+                    path: path_b,
+                    ..
+                },
+            ) => a == b && path_a == path_b,
             (Self::EmptyResponse, Self::EmptyResponse) => true,
+            (
+                Self::UnknownValueName {
+                    value: a,
+                    lang: lang_a,
+                },
+                Self::UnknownValueName {
+                    value: b,
+                    lang: lang_b,
+                },
+            ) => a == b && lang_a == lang_b,
+            (Self::InvalidQuery(a), Self::InvalidQuery(b)) => a == b,
             _ => false,
         }
     }
 }
 
-pub(crate) fn set_error<T>(response: Response<T>) -> Result<T>
+/// Decode the `response` to `url` as a `T`, or get the error it reports.
+///
+/// A TCGDEX error sent with a success status is reported as well.
+pub(crate) fn decode<T>(url: &str, response: &http::Response<String>) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let status = response.status();
+    let retry_after = response.headers().get(RETRY_AFTER);
+    let body = response.body();
+    if !status.is_success() {
+        let error = serde_json::from_str(body).ok();
+        return Err(ApiError::status(url, status, retry_after, error));
+    }
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
+    serde_path_to_error::deserialize(deserializer).map_err(|error| {
+        if let Ok(error) = serde_json::from_str::<TcgdexError>(body) {
+            let status = StatusCode::from_u16(error.status).unwrap_or(status);
+            return ApiError::status(url, status, retry_after, Some(error));
+        }
+        let path = error.path().to_string();
+        let source = error.into_inner();
+        ApiError::Deserialization {
+            url: url.to_string(),
+            path,
+            snippet: snippet(body, source.line(), source.column()),
+            source,
+        }
+    })
+}
+
+/// Get the text of `body` around `line` and `column` (from 1), as reported by `serde_json`.
+fn snippet(body: &str, line: usize, column: usize) -> String {
+    const AROUND: usize = 30;
+    let line_start: usize = body
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let offset = (line_start + column.saturating_sub(1)).min(body.len());
+    let mut start = offset.saturating_sub(AROUND);
+    while !body.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (offset + AROUND).min(body.len());
+    while !body.is_char_boundary(end) {
+        end += 1;
+    }
+    body[start..end]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Get `obj`, or an error if it is empty.
+pub(crate) fn non_empty<T>(obj: T) -> Result<T>
 where
     T: IsEmpty,
 {
-    match response {
-        Response::Data(obj) => {
-            if obj.is_empty() {
-                Err(ApiError::EmptyResponse)
-            } else {
-                Ok(obj)
+    if obj.is_empty() {
+        Err(ApiError::EmptyResponse)
+    } else {
+        Ok(obj)
+    }
+}
+
+/// Gather results of a multi-langage fetch, ignoring langages in which the object does not exist.
+pub(crate) fn collect_langs<T>(results: Vec<(Lang, Result<T>)>) -> Result<HashMap<Lang, T>> {
+    let mut objects = HashMap::new();
+    for (lang, result) in results {
+        match result {
+            Ok(object) => {
+                objects.insert(lang, object);
             }
+            Err(error) if error.is_not_found() => {}
+            Err(error) => return Err(error),
         }
-        Response::Error(error) => Err(ApiError::TcgdexApi(error)),
     }
+    Ok(objects)
 }
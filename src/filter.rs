@@ -0,0 +1,366 @@
+//! To build typed filters for a [`Query`](crate::Query).
+//!
+//! A [`Filter`] applies an [`Operator`] to a [`Field`], with one or several values (joined with OR).
+//! More details about filtering [here](https://tcgdex.dev/rest/filtering-sorting-pagination).
+//!
+//! # Example
+//!
+//! ```rust
+//! # use tcgdex_api::filter::{Field, Filter};
+//! # use tcgdex_api::Query;
+//! // Pikachu or Raichu cards with at least 60 hp
+//! let query = Query::new()
+//!     .with_filter(Filter::field(Field::NAME).eq("Pikachu").or("Raichu"))
+//!     .with_filter(Filter::field(Field::HP).gte(60));
+//! assert_eq!(query.to_string(), "name=eq:Pikachu|Raichu&hp=gte:60");
+//! ```
+
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+/// A field path usable in a filter, like `name` or `cardCount.total`.
+///
+/// Common fields are available as constants. Use [`Field::custom`] for others.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Field(Cow<'static, str>);
+
+impl Field {
+    /// Unique identifier of the object.
+    pub const ID: Self = Self(Cow::Borrowed("id"));
+
+    /// Card local ID.
+    pub const LOCAL_ID: Self = Self(Cow::Borrowed("localId"));
+
+    /// Name of the object.
+    pub const NAME: Self = Self(Cow::Borrowed("name"));
+
+    /// Card category.
+    pub const CATEGORY: Self = Self(Cow::Borrowed("category"));
+
+    /// Card illustrator.
+    pub const ILLUSTRATOR: Self = Self(Cow::Borrowed("illustrator"));
+
+    /// Card rarity.
+    pub const RARITY: Self = Self(Cow::Borrowed("rarity"));
+
+    /// The National Pokedex ID of the Pokémon on the card.
+    pub const DEX_ID: Self = Self(Cow::Borrowed("dexId"));
+
+    /// Pokémon HP.
+    pub const HP: Self = Self(Cow::Borrowed("hp"));
+
+    /// Pokémon types.
+    pub const TYPES: Self = Self(Cow::Borrowed("types"));
+
+    /// The Pokémon name it evolve from.
+    pub const EVOLVE_FROM: Self = Self(Cow::Borrowed("evolveFrom"));
+
+    /// Pokémon stage.
+    pub const STAGE: Self = Self(Cow::Borrowed("stage"));
+
+    /// Card suffix.
+    pub const SUFFIX: Self = Self(Cow::Borrowed("suffix"));
+
+    /// Pokémon retreat cost.
+    pub const RETREAT: Self = Self(Cow::Borrowed("retreat"));
+
+    /// Card regulation mark.
+    pub const REGULATION_MARK: Self = Self(Cow::Borrowed("regulationMark"));
+
+    /// Last card update, like `2024-06-18T00:33:12+02:00`.
+    pub const UPDATED: Self = Self(Cow::Borrowed("updated"));
+
+    /// Type of trainer card.
+    pub const TRAINER_TYPE: Self = Self(Cow::Borrowed("trainerType"));
+
+    /// Type of energy card.
+    pub const ENERGY_TYPE: Self = Self(Cow::Borrowed("energyType"));
+
+    /// Id of the card set.
+    pub const SET_ID: Self = Self(Cow::Borrowed("set.id"));
+
+    /// Name of the card set.
+    pub const SET_NAME: Self = Self(Cow::Borrowed("set.name"));
+
+    /// Total amount of cards in a set, including hidden.
+    pub const CARD_COUNT_TOTAL: Self = Self(Cow::Borrowed("cardCount.total"));
+
+    /// Amount of official cards in a set.
+    pub const CARD_COUNT_OFFICIAL: Self = Self(Cow::Borrowed("cardCount.official"));
+
+    /// Set release date in the form yyyy-mm-dd.
+    pub const RELEASE_DATE: Self = Self(Cow::Borrowed("releaseDate"));
+
+    /// Id of the set serie.
+    pub const SERIE_ID: Self = Self(Cow::Borrowed("serie.id"));
+
+    /// Usability in standard competitions.
+    pub const LEGAL_STANDARD: Self = Self(Cow::Borrowed("legal.standard"));
+
+    /// Usability in expanded competitions.
+    pub const LEGAL_EXPANDED: Self = Self(Cow::Borrowed("legal.expanded"));
+
+    /// Create a field from its path, like `variants.holo`.
+    #[must_use]
+    pub fn custom(path: &str) -> Self {
+        Self(Cow::Owned(path.to_string()))
+    }
+
+    /// Get the field path.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Comparison done by a [`Filter`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operator {
+    /// Field contains the value, case insensitive. Default TCGdex behavior.
+    Like,
+
+    /// Field does not contain the value, case insensitive.
+    NotLike,
+
+    /// Field is strictly equal to the value.
+    Eq,
+
+    /// Field is strictly different from the value.
+    Neq,
+
+    /// Field is greater than the value.
+    Gt,
+
+    /// Field is greater than or equal to the value.
+    Gte,
+
+    /// Field is lesser than the value.
+    Lt,
+
+    /// Field is lesser than or equal to the value.
+    Lte,
+
+    /// Field is not set.
+    Null,
+
+    /// Field is set.
+    NotNull,
+}
+
+impl Operator {
+    const ALL: [Self; 10] = [
+        Self::Like,
+        Self::NotLike,
+        Self::Eq,
+        Self::Neq,
+        Self::Gt,
+        Self::Gte,
+        Self::Lt,
+        Self::Lte,
+        Self::Null,
+        Self::NotNull,
+    ];
+
+    /// Get the prefix of the value in the query string.
+    const fn prefix(self) -> &'static str {
+        match self {
+            Self::Like => "",
+            Self::NotLike => "not:",
+            Self::Eq => "eq:",
+            Self::Neq => "neq:",
+            Self::Gt => "gt:",
+            Self::Gte => "gte:",
+            Self::Lt => "lt:",
+            Self::Lte => "lte:",
+            Self::Null => "null:",
+            Self::NotNull => "notnull:",
+        }
+    }
+
+    /// Split a raw filter value into its operator and its values, like `gte:60` into `Gte` and `60`.
+    pub(crate) fn split(value: &str) -> (Self, &str) {
+        if let Some((prefix, rest)) = value.split_once(':') {
+            let operator = match prefix {
+                "like" => Some(Self::Like),
+                "notlike" => Some(Self::NotLike),
+                _ => Self::ALL
+                    .into_iter()
+                    .find(|o| o.prefix().strip_suffix(':') == Some(prefix)),
+            };
+            if let Some(operator) = operator {
+                return (operator, rest);
+            }
+        }
+        (Self::Like, value)
+    }
+}
+
+/// A typed filter on a [`Field`].
+///
+/// Create it with [`Filter::field`], then choose the operator. Values are URL encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    field: Field,
+    operator: Operator,
+    values: Vec<String>,
+}
+
+impl Filter {
+    /// Start a filter on `field`. Without operator, it matches objects where the field is set.
+    #[must_use]
+    pub const fn field(field: Field) -> Self {
+        Self {
+            field,
+            operator: Operator::NotNull,
+            values: Vec::new(),
+        }
+    }
+
+    /// Set operator and value.
+    #[must_use]
+    pub fn with(mut self, operator: Operator, value: impl Display) -> Self {
+        self.operator = operator;
+        self.values = vec![value.to_string()];
+        self
+    }
+
+    /// Field contains `value`, case insensitive.
+    #[must_use]
+    pub fn like(self, value: impl Display) -> Self {
+        self.with(Operator::Like, value)
+    }
+
+    /// Field does not contain `value`, case insensitive.
+    #[must_use]
+    pub fn not_like(self, value: impl Display) -> Self {
+        self.with(Operator::NotLike, value)
+    }
+
+    /// Field is strictly equal to `value`.
+    #[must_use]
+    pub fn eq(self, value: impl Display) -> Self {
+        self.with(Operator::Eq, value)
+    }
+
+    /// Field is strictly different from `value`.
+    #[must_use]
+    pub fn neq(self, value: impl Display) -> Self {
+        self.with(Operator::Neq, value)
+    }
+
+    /// Field is greater than `value`.
+    #[must_use]
+    pub fn gt(self, value: impl Display) -> Self {
+        self.with(Operator::Gt, value)
+    }
+
+    /// Field is greater than or equal to `value`.
+    #[must_use]
+    pub fn gte(self, value: impl Display) -> Self {
+        self.with(Operator::Gte, value)
+    }
+
+    /// Field is lesser than `value`.
+    #[must_use]
+    pub fn lt(self, value: impl Display) -> Self {
+        self.with(Operator::Lt, value)
+    }
+
+    /// Field is lesser than or equal to `value`.
+    #[must_use]
+    pub fn lte(self, value: impl Display) -> Self {
+        self.with(Operator::Lte, value)
+    }
+
+    /// Field is not set.
+    #[must_use]
+    pub fn null(mut self) -> Self {
+        self.operator = Operator::Null;
+        self.values.clear();
+        self
+    }
+
+    /// Field is set.
+    #[must_use]
+    pub fn not_null(mut self) -> Self {
+        self.operator = Operator::NotNull;
+        self.values.clear();
+        self
+    }
+
+    /// Add an alternative `value` for the same operator (OR).
+    #[must_use]
+    pub fn or(mut self, value: impl Display) -> Self {
+        self.values.push(value.to_string());
+        self
+    }
+}
+
+impl Display for Filter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values: Vec<String> = self.values.iter().map(|v| percent_encode(v)).collect();
+        write!(
+            f,
+            "{}={}{}",
+            self.field,
+            self.operator.prefix(),
+            values.join("|")
+        )
+    }
+}
+
+/// Encode a raw filter like `name=eq:Sword & Shield|Scarlet`.
+///
+/// Values are percent encoded, while operator prefix and OR separators are kept.
+pub(crate) fn encode_raw(filter: &str) -> String {
+    let Some((field, value)) = filter.split_once('=') else {
+        return percent_encode(filter);
+    };
+    let (_, values) = Operator::split(value);
+    let prefix = &value[..value.len() - values.len()];
+    let values: Vec<String> = values.split('|').map(percent_encode).collect();
+    format!("{field}={prefix}{}", values.join("|"))
+}
+
+/// Encode `value` to be used in an URL: every byte except unreserved characters is percent encoded.
+pub(crate) fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// Decode a percent encoded `value`. Invalid sequences are kept as is.
+pub(crate) fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
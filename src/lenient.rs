@@ -0,0 +1,252 @@
+//! Decode responses leniently, reporting what could not be decoded as is.
+//!
+//! A strict decode fails on the first unexpected value. A lenient decode replaces malformed or missing
+//! values by their default value, drops malformed list items, and returns a [`DecodeWarning`] for each of them.
+//! Unknown fields are kept in the `extra` map of [`Card`], [`Set`] and [`Serie`], and reported too.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use tcgdex_api::Tcgdex;
+//! let tcgdex = Tcgdex::new();
+//! let card = tcgdex.cards().fetch_lenient("swsh3-136").unwrap();
+//! for warning in &card.warnings {
+//!     println!("{warning}");
+//! }
+//! println!("card = {:?}", card.value);
+//! ```
+
+use crate::errors::{self, ApiError};
+use crate::{Card, Serie, Set};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+use serde_path_to_error::Segment;
+use std::fmt::{Display, Formatter};
+
+/// Maximum number of coercions before giving up.
+const MAX_COERCIONS: usize = 256;
+
+/// A decoded object with the warnings of its decoding.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Lenient<T> {
+    /// The decoded object.
+    pub value: T,
+
+    /// What could not be decoded as is, in order of discovery.
+    pub warnings: Vec<DecodeWarning>,
+}
+
+/// What was done with a value that could not be decoded as is.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Coercion {
+    /// The value is malformed or missing, the default value is used.
+    Defaulted,
+
+    /// The list item is malformed and was dropped from its list.
+    Dropped,
+
+    /// The field is unknown and was kept in the `extra` map.
+    Unknown,
+}
+
+/// A value of a response that could not be decoded as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeWarning {
+    /// The path of the value in the response, like `attacks[0].damage`.
+    pub path: String,
+
+    /// What was done with the value.
+    pub coercion: Coercion,
+
+    /// Why the value could not be decoded.
+    pub message: String,
+}
+
+impl Display for DecodeWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let action = match self.coercion {
+            Coercion::Defaulted => "default value used",
+            Coercion::Dropped => "item dropped",
+            Coercion::Unknown => "kept in extra",
+        };
+        write!(f, "{} : {} ({action})", self.path, self.message)
+    }
+}
+
+/// Objects keeping their unknown fields.
+pub(crate) trait Extra {
+    /// Get the unknown fields.
+    fn extra(&self) -> &Map<String, Value>;
+}
+
+impl Extra for Card {
+    fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+impl Extra for Set {
+    fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+impl Extra for Serie {
+    fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// Decode `value`, the response to `url`, coercing the values that cannot be decoded.
+///
+/// Fails only if the response is not an object, or cannot be fixed.
+pub(crate) fn decode<T>(url: &str, mut value: Value) -> errors::Result<Lenient<T>>
+where
+    T: DeserializeOwned + Extra,
+{
+    let mut warnings: Vec<DecodeWarning> = Vec::new();
+    let mut coercions = 0;
+    let failure = loop {
+        let error = match serde_path_to_error::deserialize::<_, T>(&value) {
+            Ok(decoded) => {
+                warnings.extend(decoded.extra().keys().map(|key| DecodeWarning {
+                    path: key.clone(),
+                    coercion: Coercion::Unknown,
+                    message: "unknown field".to_string(),
+                }));
+                return Ok(Lenient {
+                    value: decoded,
+                    warnings,
+                });
+            }
+            Err(error) => error,
+        };
+        if coercions == MAX_COERCIONS {
+            break error;
+        }
+        coercions += 1;
+        let message = error.inner().to_string();
+        let segments: Vec<Segment> = error.path().iter().cloned().collect();
+        let warning = if let Some(field) = missing_field(&message) {
+            fill::<T>(&mut value, &segments, field).map(|path| (path, Coercion::Defaulted))
+        } else {
+            remove(&mut value, &segments).map(|coercion| (error.path().to_string(), coercion))
+        };
+        let Some((path, coercion)) = warning else {
+            break error;
+        };
+        // a malformed field removed then filled is a single coercion.
+        if warnings.last().map(|last| &last.path) != Some(&path) {
+            warnings.push(DecodeWarning {
+                path,
+                coercion,
+                message,
+            });
+        }
+    };
+    let path = failure.path().to_string();
+    let snippet = pointer(&value, failure.path().iter())
+        .map(|value| value.to_string().chars().take(60).collect())
+        .unwrap_or_default();
+    Err(ApiError::Deserialization {
+        url: url.to_string(),
+        path,
+        snippet,
+        source: failure.into_inner(),
+    })
+}
+
+/// Get the name of the field of a "missing field" error.
+fn missing_field(message: &str) -> Option<&str> {
+    message.strip_prefix("missing field `")?.split('`').next()
+}
+
+/// Get the value at `segments` path.
+fn pointer<'v, 's>(
+    value: &'v Value,
+    segments: impl Iterator<Item = &'s Segment>,
+) -> Option<&'v Value> {
+    let mut value = value;
+    for segment in segments {
+        value = match segment {
+            Segment::Seq { index } => value.get(index)?,
+            Segment::Map { key } | Segment::Enum { variant: key } => value.get(key)?,
+            Segment::Unknown => return None,
+        };
+    }
+    Some(value)
+}
+
+/// Get the value at `segments` path, mutably.
+fn pointer_mut<'v>(value: &'v mut Value, segments: &[Segment]) -> Option<&'v mut Value> {
+    let mut value = value;
+    for segment in segments {
+        value = match segment {
+            Segment::Seq { index } => value.get_mut(index)?,
+            Segment::Map { key } | Segment::Enum { variant: key } => value.get_mut(key)?,
+            Segment::Unknown => return None,
+        };
+    }
+    Some(value)
+}
+
+/// Remove the malformed value at `segments` path, so its default value is used.
+fn remove(value: &mut Value, segments: &[Segment]) -> Option<Coercion> {
+    let (last, parent) = segments.split_last()?;
+    let parent = pointer_mut(value, parent)?;
+    match last {
+        Segment::Seq { index } => {
+            let items = parent.as_array_mut()?;
+            (*index < items.len()).then(|| items.remove(*index))?;
+            Some(Coercion::Dropped)
+        }
+        Segment::Map { key } | Segment::Enum { variant: key } => {
+            parent.as_object_mut()?.remove(key)?;
+            Some(Coercion::Defaulted)
+        }
+        Segment::Unknown => None,
+    }
+}
+
+/// Fill the missing `field` of the object at `segments` path with a default value. Returns the field path.
+///
+/// The field type is unknown: the first default value accepted by `T` decoder is used.
+fn fill<T>(value: &mut Value, segments: &[Segment], field: &str) -> Option<String>
+where
+    T: DeserializeOwned,
+{
+    let mut path = segments.to_vec();
+    path.push(Segment::Map {
+        key: field.to_string(),
+    });
+    let path = path_text(&path);
+    for candidate in [json!(""), json!(0), json!([]), json!({}), json!(false)] {
+        pointer_mut(value, segments)?
+            .as_object_mut()?
+            .insert(field.to_string(), candidate);
+        // rejected if the field has a wrong type, accepted if only its own fields are missing.
+        let accepted = match serde_path_to_error::deserialize::<_, T>(&*value) {
+            Ok(_) => true,
+            Err(error) => {
+                error.path().to_string() != path
+                    || missing_field(&error.inner().to_string()).is_some()
+            }
+        };
+        if accepted {
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// Format a path like `serde_path_to_error` does.
+fn path_text(segments: &[Segment]) -> String {
+    let mut text = String::new();
+    for (i, segment) in segments.iter().enumerate() {
+        if i > 0 && !matches!(segment, Segment::Seq { .. }) {
+            text.push('.');
+        }
+        text.push_str(&segment.to_string());
+    }
+    text
+}
@@ -1,7 +1,8 @@
 //! This library allow to interact with [TCGdex API](https://tcgdex.dev).
 //!
 //! You can get information about Pokémon cards, cards sets and sets series accessible through a powerful filter.
-//! You can also get a list of all existing card types, categories, Hp, illustrators, retreat costs and rarities.
+//! You can also get a list of all existing card types, categories, Hp, illustrators, retreat costs, rarities,
+//! stages, suffixes, trainer types, energy types, regulation marks, variants and National Pokédex ids.
 
 #![deny(
     missing_docs,
@@ -15,32 +16,83 @@
     unused_qualifications
 )]
 
+pub mod assets;
+#[cfg(feature = "async")]
+pub mod asynchronous;
+pub mod builder;
+pub mod cache;
+#[cfg(feature = "cbor")]
+pub mod cbor;
 pub mod endpoints;
 pub mod errors;
+pub mod filter;
 mod is_empty;
+pub mod lenient;
+pub mod mirror;
+mod offline;
+pub mod pagination;
+mod pipeline;
 pub mod query;
+pub mod retry;
+pub mod snapshot;
+mod string_enum;
+#[cfg(feature = "test-support")]
+pub mod test_support;
 
-use crate::endpoints::cards::CardApi;
+use crate::cache::CacheKey;
+use crate::endpoints::cards::{CardApi, CardsFor};
 use crate::endpoints::categories::CategoryApi;
+use crate::endpoints::dex_ids::DexIdApi;
+use crate::endpoints::energy_types::EnergyTypeApi;
 use crate::endpoints::hps::HpApi;
 use crate::endpoints::illustrators::IllustratorApi;
 use crate::endpoints::rarities::RarityApi;
+use crate::endpoints::regulation_marks::RegulationMarkApi;
 use crate::endpoints::retreats::RetreatApi;
 use crate::endpoints::series::SerieApi;
 use crate::endpoints::sets::SetApi;
+use crate::endpoints::stages::StageApi;
+use crate::endpoints::suffixes::SuffixApi;
+use crate::endpoints::trainer_types::TrainerTypeApi;
 use crate::endpoints::types::TypeApi;
+use crate::endpoints::variants::VariantApi;
+use crate::errors::{ApiError, UnknownLangError};
+use crate::is_empty::IsEmpty;
+use crate::lenient::{Extra, Lenient};
+use crate::pipeline::{Pipeline, Start};
+use crate::query::{endpoint_url, URL_BASE};
+use reqwest::header::{HeaderMap, CONTENT_LENGTH, RETRY_AFTER};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 // Re-exports
+pub use crate::builder::TcgdexBuilder;
 pub use crate::endpoints::{
-    cards::{Attack, Card, CardBrief, Variants, Weakness},
+    cards::{
+        Ability, Attack, Booster, Card, CardBrief, Damage, DamageModifier, EnergyType, Resistance,
+        Stage, TrainerType, VariantDetail, Variants, Weakness,
+    },
+    categories::Category,
+    rarities::Rarity,
     series::{Serie, SerieBrief},
     sets::{Set, SetBrief},
+    types::PokemonType,
 };
+pub use crate::filter::{Field, Filter};
 pub use crate::query::Query;
 
+#[cfg(feature = "async")]
+pub use crate::asynchronous::AsyncTcgdex;
+
 /// Available langages for data.
-#[derive(Debug, Copy, Clone)]
+///
+/// A langage can be parsed from a BCP-47 tag like `en`, `pt-BR` or `zh-Hant`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Lang {
     /// English
     EN,
@@ -57,13 +109,263 @@ pub enum Lang {
     /// Portuguese
     PT,
 
+    /// Brazilian Portuguese
+    PtBR,
+
+    /// European Portuguese
+    PtPT,
+
     /// Spanish
     ES,
+
+    /// Mexican Spanish
+    EsMX,
+
+    /// Dutch
+    NL,
+
+    /// Polish
+    PL,
+
+    /// Russian
+    RU,
+
+    /// Japanese
+    JA,
+
+    /// Korean
+    KO,
+
+    /// Simplified Chinese
+    ZhCN,
+
+    /// Traditional Chinese
+    ZhTW,
+
+    /// Indonesian
+    ID,
+
+    /// Thai
+    TH,
+}
+
+impl Lang {
+    /// All available langages.
+    pub const ALL: [Self; 18] = [
+        Self::EN,
+        Self::FR,
+        Self::DE,
+        Self::IT,
+        Self::PT,
+        Self::PtBR,
+        Self::PtPT,
+        Self::ES,
+        Self::EsMX,
+        Self::NL,
+        Self::PL,
+        Self::RU,
+        Self::JA,
+        Self::KO,
+        Self::ZhCN,
+        Self::ZhTW,
+        Self::ID,
+        Self::TH,
+    ];
+
+    /// Get the langage code used in TCGdex URLs.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::EN => "en",
+            Self::FR => "fr",
+            Self::DE => "de",
+            Self::IT => "it",
+            Self::PT => "pt",
+            Self::PtBR => "pt-br",
+            Self::PtPT => "pt-pt",
+            Self::ES => "es",
+            Self::EsMX => "es-mx",
+            Self::NL => "nl",
+            Self::PL => "pl",
+            Self::RU => "ru",
+            Self::JA => "ja",
+            Self::KO => "ko",
+            Self::ZhCN => "zh-cn",
+            Self::ZhTW => "zh-tw",
+            Self::ID => "id",
+            Self::TH => "th",
+        }
+    }
+
+    /// Returns true if this library knows the names of typed values (types, categories, rarities, stages,
+    /// trainer and energy types) in this langage.
+    ///
+    /// In other langages (`NL`, `PL`, `RU`, `JA`, `KO`, `ZhCN`, `ZhTW`, `ID` and `TH`), typed values are decoded
+    /// as `Unknown` with the name returned by the API, and cannot be requested by name: see
+    /// [`PokemonType::try_localized`] for example.
+    #[must_use]
+    pub const fn has_value_names(self) -> bool {
+        matches!(
+            self.parent(),
+            Self::EN | Self::FR | Self::DE | Self::IT | Self::PT | Self::ES
+        )
+    }
+
+    /// Get the langage sharing translations with a regional langage (`PT` for `PtBR` for example).
+    pub(crate) const fn parent(self) -> Self {
+        match self {
+            Self::PtBR | Self::PtPT => Self::PT,
+            Self::EsMX => Self::ES,
+            _ => self,
+        }
+    }
 }
 
 impl Display for Lang {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for Lang {
+    type Err = UnknownLangError;
+
+    /// Parse a BCP-47 tag, case insensitive. Unknown region or script subtags are ignored.
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        let tag = tag.trim().replace('_', "-").to_lowercase();
+        let mut subtags = tag.split('-');
+        let language = subtags.next().unwrap_or_default();
+        let subtags: Vec<&str> = subtags.collect();
+        let has = |subtag: &str| subtags.contains(&subtag);
+
+        let lang = match language {
+            "en" => Self::EN,
+            "fr" => Self::FR,
+            "de" => Self::DE,
+            "it" => Self::IT,
+            "pt" if has("br") => Self::PtBR,
+            "pt" if has("pt") => Self::PtPT,
+            "pt" => Self::PT,
+            "es" if has("mx") => Self::EsMX,
+            "es" => Self::ES,
+            "nl" => Self::NL,
+            "pl" => Self::PL,
+            "ru" => Self::RU,
+            "ja" => Self::JA,
+            "ko" => Self::KO,
+            "zh" if has("hant") || has("tw") || has("hk") || has("mo") => Self::ZhTW,
+            "zh" => Self::ZhCN,
+            "id" | "in" => Self::ID,
+            "th" => Self::TH,
+            _ => return Err(UnknownLangError(tag)),
+        };
+        Ok(lang)
+    }
+}
+
+/// Maximum number of requests sent at once by bulk operations.
+pub(crate) const MAX_CONCURRENCY: usize = 8;
+
+/// Apply `task` to each of `items` on up to [`MAX_CONCURRENCY`] threads. Results are in order of `items`.
+pub(crate) fn map_concurrently<I, R, F>(items: &[I], task: F) -> Vec<R>
+where
+    I: Sync,
+    R: Send,
+    F: Fn(&I) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, R)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..MAX_CONCURRENCY.min(items.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    // each worker takes the next item until all are done.
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break;
+                        };
+                        results.push((index, task(item)));
+                    }
+                    results
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+            .collect()
+    });
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Write `content` to the file at `path` through a temporary file, so a reader never sees a partial file.
+///
+/// The temporary file is unique to the process and the call: concurrent writers of the same file do not
+/// mix their content, the last renamed one wins.
+pub(crate) fn write_atomically(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    static WRITES: AtomicUsize = AtomicUsize::new(0);
+    let write = WRITES.fetch_add(1, Ordering::Relaxed);
+    let tmp = path.with_extension(format!("{}-{write}.tmp", std::process::id()));
+    let result = std::fs::write(&tmp, content).and_then(|()| std::fs::rename(&tmp, path));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// Get `ids` without duplicates, in order of first occurrence.
+pub(crate) fn unique_ids(ids: &[impl AsRef<str>]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    ids.iter()
+        .map(AsRef::as_ref)
+        .filter(|id| seen.insert(*id))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Returns true if `value` is the default of its type, to skip it when serializing a model.
+pub(crate) fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// Build an HTTP response received from the network, to decode it.
+pub(crate) fn http_response<B>(
+    status: StatusCode,
+    headers: HeaderMap,
+    body: B,
+) -> http::Response<B> {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = status;
+    *response.headers_mut() = headers;
+    response
+}
+
+/// Decode a response body as text, replacing invalid UTF-8 sequences.
+pub(crate) fn text(body: Vec<u8>) -> String {
+    String::from_utf8(body)
+        .unwrap_or_else(|error| String::from_utf8_lossy(error.as_bytes()).into_owned())
+}
+
+/// Get the size announced by the `Content-Length` header, if any.
+pub(crate) fn content_length(headers: &HeaderMap) -> Option<u64> {
+    headers.get(CONTENT_LENGTH)?.to_str().ok()?.parse().ok()
+}
+
+/// Get the cache key of `url`: its langage is the first path segment after `base_url`.
+pub(crate) fn cache_key(base_url: &str, lang: Lang, url: &str) -> CacheKey {
+    let url_lang = url
+        .strip_prefix(base_url)
+        .and_then(|path| path.split('/').next())
+        .and_then(|code| code.parse().ok());
+    CacheKey {
+        lang: url_lang.unwrap_or(lang),
+        url: url.to_string(),
     }
 }
 
@@ -74,10 +376,16 @@ impl Display for Lang {
 /// Langage can be changed. English is default value.
 ///
 /// You have to get module interface before using a module.
+///
+/// Use [`TcgdexBuilder`] to change API base URL or HTTP client settings.
+///
+/// All requests are blocking. See `AsyncTcgdex` (`async` feature) for a non-blocking client.
 #[derive(Debug)]
 pub struct Tcgdex {
     client: reqwest::blocking::Client,
     lang: Lang,
+    base_url: String,
+    pipeline: Pipeline,
 }
 
 impl Default for Tcgdex {
@@ -93,9 +401,17 @@ impl Tcgdex {
         Self {
             client: reqwest::blocking::Client::new(),
             lang: Lang::EN,
+            base_url: URL_BASE.to_string(),
+            pipeline: Pipeline::default(),
         }
     }
 
+    /// Get a [`TcgdexBuilder`] to configure a new instance.
+    #[must_use]
+    pub fn builder() -> TcgdexBuilder {
+        TcgdexBuilder::new()
+    }
+
     /// Set cards language.
     pub fn set_lang(&mut self, lang: Lang) {
         self.lang = lang;
@@ -103,55 +419,244 @@ impl Tcgdex {
 
     /// Get an interface to types module.
     #[must_use]
-    pub fn types(&self) -> TypeApi {
-        TypeApi(&self.client, self.lang.to_string().to_lowercase())
+    pub fn types(&self) -> TypeApi<'_> {
+        TypeApi(self)
     }
 
     /// Get an interface to categories module.
     #[must_use]
-    pub fn categories(&self) -> CategoryApi {
-        CategoryApi(&self.client, self.lang.to_string().to_lowercase())
+    pub fn categories(&self) -> CategoryApi<'_> {
+        CategoryApi(self)
     }
 
     /// Get an interface to hps module.
     #[must_use]
-    pub fn hps(&self) -> HpApi {
-        HpApi(&self.client, self.lang.to_string().to_lowercase())
+    pub fn hps(&self) -> HpApi<'_> {
+        HpApi(self)
     }
 
     /// Get an interface to illustrators module.
     #[must_use]
-    pub fn illustrators(&self) -> IllustratorApi {
-        IllustratorApi(&self.client, self.lang.to_string().to_lowercase())
+    pub fn illustrators(&self) -> IllustratorApi<'_> {
+        IllustratorApi(self)
     }
 
     /// Get an interface to rarities module.
     #[must_use]
-    pub fn rarities(&self) -> RarityApi {
-        RarityApi(&self.client, self.lang.to_string().to_lowercase())
+    pub fn rarities(&self) -> RarityApi<'_> {
+        RarityApi(self)
     }
 
     /// Get an interface to retreats module.
     #[must_use]
-    pub fn retreats(&self) -> RetreatApi {
-        RetreatApi(&self.client, self.lang.to_string().to_lowercase())
+    pub fn retreats(&self) -> RetreatApi<'_> {
+        RetreatApi(self)
     }
 
-    /// Get an interface to retreats module.
+    /// Get an interface to stages module.
     #[must_use]
-    pub fn series(&self) -> SerieApi {
-        SerieApi(&self.client, self.lang.to_string().to_lowercase())
+    pub fn stages(&self) -> StageApi<'_> {
+        StageApi(self)
     }
 
-    /// Get an interface to retreats module.
+    /// Get an interface to suffixes module.
     #[must_use]
-    pub fn sets(&self) -> SetApi {
-        SetApi(&self.client, self.lang.to_string().to_lowercase())
+    pub fn suffixes(&self) -> SuffixApi<'_> {
+        SuffixApi(self)
     }
 
-    /// Get an interface to retreats module.
+    /// Get an interface to trainer types module.
+    #[must_use]
+    pub fn trainer_types(&self) -> TrainerTypeApi<'_> {
+        TrainerTypeApi(self)
+    }
+
+    /// Get an interface to energy types module.
+    #[must_use]
+    pub fn energy_types(&self) -> EnergyTypeApi<'_> {
+        EnergyTypeApi(self)
+    }
+
+    /// Get an interface to regulation marks module.
+    #[must_use]
+    pub fn regulation_marks(&self) -> RegulationMarkApi<'_> {
+        RegulationMarkApi(self)
+    }
+
+    /// Get an interface to variants module.
+    #[must_use]
+    pub fn variants(&self) -> VariantApi<'_> {
+        VariantApi(self)
+    }
+
+    /// Get an interface to dex ids module.
+    #[must_use]
+    pub fn dex_ids(&self) -> DexIdApi<'_> {
+        DexIdApi(self)
+    }
+
+    /// Get an interface to series module.
+    #[must_use]
+    pub fn series(&self) -> SerieApi<'_> {
+        SerieApi(self)
+    }
+
+    /// Get an interface to sets module.
+    #[must_use]
+    pub fn sets(&self) -> SetApi<'_> {
+        SetApi(self)
+    }
+
+    /// Get an interface to cards module.
     #[must_use]
-    pub fn cards(&self) -> CardApi {
-        CardApi(&self.client, self.lang.to_string().to_lowercase())
+    pub fn cards(&self) -> CardApi<'_> {
+        CardApi(self)
+    }
+
+    /// Build the URL to reach `object_name` endpoint with current langage.
+    pub(crate) fn url(&self, object_name: &str, query: Option<&Query>) -> String {
+        endpoint_url(&self.base_url, self.lang, object_name, query)
+    }
+
+    /// Send a GET request to `url` and decode the JSON body.
+    ///
+    /// Every endpoint goes through this function. Responses are taken from the snapshot or the cache, if any.
+    /// Requests sent are rate limited and retried, if configured.
+    pub(crate) fn get<T>(&self, url: &str) -> errors::Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self
+            .respond(url)
+            .map_err(|error| ApiError::request(url, error))?;
+        errors::decode(url, &response)
+    }
+
+    /// Get the response to a GET request to `url`, from the snapshot, the cache or the network.
+    fn respond(&self, url: &str) -> reqwest::Result<http::Response<String>> {
+        let pending = match self.pipeline.start(&self.base_url, self.lang, url) {
+            Start::Done(response) => return Ok(response),
+            Start::Send(pending) => pending,
+        };
+        let response = self.send(self.client.get(url).headers(pending.headers.clone()))?;
+        Ok(self.pipeline.finish(pending, response))
+    }
+
+    /// Download the file at `url`, with its size announced by the server, if any.
+    pub(crate) fn download(&self, url: &str) -> errors::Result<(Vec<u8>, Option<u64>)> {
+        let response = self
+            .send(self.client.get(url))
+            .map_err(|error| ApiError::request(url, error))?;
+        let status = response.status();
+        if !status.is_success() {
+            let retry_after = response.headers().get(RETRY_AFTER);
+            return Err(ApiError::status(url, status, retry_after, None));
+        }
+        let size = content_length(response.headers());
+        Ok((response.into_body(), size))
+    }
+
+    /// Send `request` after waiting for the rate limiter, and send it again while the retry policy allows.
+    ///
+    /// The body is read in each attempt, so a connection reset while reading it is retried too.
+    fn send(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> reqwest::Result<http::Response<Vec<u8>>> {
+        let mut attempt = 1;
+        loop {
+            // a request with a streamed body cannot be cloned, so it is sent only once.
+            let Some(next) = request.try_clone() else {
+                return self.send_once(request);
+            };
+            let result = self.send_once(next);
+            let Some(delay) = self.pipeline.retry_delay(attempt, &result) else {
+                return result;
+            };
+            std::thread::sleep(delay);
+            attempt += 1;
+        }
+    }
+
+    /// Send `request` after waiting for the rate limiter, and read its body.
+    fn send_once(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> reqwest::Result<http::Response<Vec<u8>>> {
+        self.throttle();
+        let response = request.send()?;
+        let (status, headers) = (response.status(), response.headers().clone());
+        Ok(http_response(status, headers, response.bytes()?.to_vec()))
+    }
+
+    /// Wait for the rate limiter, if any.
+    fn throttle(&self) {
+        if let Some(delay) = self.pipeline.throttle_delay() {
+            std::thread::sleep(delay);
+        }
+    }
+
+    /// Fetch object `id` of `object_name` endpoint in each langage of `langs`, at most [`MAX_CONCURRENCY`] at once.
+    ///
+    /// Langages in which the object does not exist are not in the returned map.
+    pub(crate) fn fetch_langs<T>(
+        &self,
+        object_name: &str,
+        id: &str,
+        langs: &[Lang],
+    ) -> errors::Result<HashMap<Lang, T>>
+    where
+        T: DeserializeOwned + IsEmpty + Send,
+    {
+        let query = Query::new().with_id(id);
+        let results = map_concurrently(langs, |&lang| {
+            let url = endpoint_url(&self.base_url, lang, object_name, Some(&query));
+            let result = self.get::<T>(&url).and_then(errors::non_empty);
+            (lang, result)
+        });
+        errors::collect_langs(results)
+    }
+
+    /// Fetch object `id` of `object_name` endpoint, decoding it leniently.
+    pub(crate) fn fetch_lenient<T>(&self, object_name: &str, id: &str) -> errors::Result<Lenient<T>>
+    where
+        T: DeserializeOwned + IsEmpty + Extra,
+    {
+        let url = self.url(object_name, Some(&Query::new().with_id(id)));
+        let lenient: Lenient<T> = lenient::decode(&url, self.get(&url)?)?;
+        if lenient.value.is_empty() {
+            return Err(ApiError::EmptyResponse);
+        }
+        Ok(lenient)
+    }
+
+    /// Fetch the cards with `value` of `object_name` list endpoint, like `types/Fire`.
+    pub(crate) fn cards_for(
+        &self,
+        object_name: &str,
+        value: &str,
+    ) -> errors::Result<Vec<CardBrief>> {
+        let url = self.url(object_name, Some(&Query::new().with_id(value)));
+        let cards: CardsFor = self.get(&url)?;
+        errors::non_empty(cards.cards)
+    }
+
+    /// Fetch objects `ids` of `object_name` endpoint, at most [`MAX_CONCURRENCY`] at once.
+    ///
+    /// Results are in order of first occurrence of their id, duplicates being fetched once.
+    pub(crate) fn fetch_many<T>(
+        &self,
+        object_name: &str,
+        ids: &[impl AsRef<str>],
+    ) -> Vec<(String, errors::Result<T>)>
+    where
+        T: DeserializeOwned + IsEmpty + Send,
+    {
+        let ids = unique_ids(ids);
+        let results = map_concurrently(&ids, |id| {
+            let url = self.url(object_name, Some(&Query::new().with_id(id)));
+            self.get::<T>(&url).and_then(errors::non_empty)
+        });
+        ids.into_iter().zip(results).collect()
     }
 }
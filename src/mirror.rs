@@ -0,0 +1,298 @@
+//! Download card, set and serie images into a local directory with an [`ImageMirror`].
+//!
+//! Directory layout:
+//! - `objects/ab/ab0123456789cdef.webp`: images, named by a hash of their content, so an image shared by several
+//!   objects is stored once.
+//! - `index.jsonl`: one line by downloaded image, with its URL, hash and size.
+//!
+//! The index is written after each download: an interrupted download resumes where it stopped, skipping images
+//! already in the mirror with their expected size.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use tcgdex_api::{Query, Set, Tcgdex};
+//! use tcgdex_api::mirror::ImageMirror;
+//! let tcgdex = Tcgdex::new();
+//! let set: Set = tcgdex.sets().fetch(Some(&Query::new().with_id("swsh3"))).unwrap();
+//! let mirror = ImageMirror::new(&tcgdex, "images").unwrap();
+//! let report = mirror.download_set(&set);
+//! println!("{} downloaded, {} failed", report.downloaded.len(), report.failed.len());
+//! ```
+
+use crate::assets::{CardImage, Extension, Image, Quality};
+use crate::cache::fnv1a;
+use crate::errors::MirrorError;
+use crate::snapshot::Snapshot;
+use crate::{Card, Set, Tcgdex};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+const INDEX: &str = "index.jsonl";
+const OBJECTS: &str = "objects";
+
+/// A line of the mirror index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexEntry {
+    url: String,
+    hash: String,
+    size: u64,
+}
+
+/// Result of the download of several images.
+#[derive(Debug, Default)]
+pub struct MirrorReport {
+    /// URLs of the downloaded images.
+    pub downloaded: Vec<String>,
+
+    /// URLs of the images already in the mirror.
+    pub skipped: Vec<String>,
+
+    /// Objects without image, like `swsh3-136` or `swsh3 logo`.
+    pub missing: Vec<String>,
+
+    /// URLs of the images that could not be downloaded, with the reason.
+    pub failed: Vec<(String, MirrorError)>,
+}
+
+impl MirrorReport {
+    /// Returns true if all images are in the mirror.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// A local copy of TCGdex images. See [`mirror`](crate::mirror) module.
+///
+/// Images are downloaded with the client settings: rate limit and retries apply.
+#[derive(Debug)]
+pub struct ImageMirror<'t> {
+    tcgdex: &'t Tcgdex,
+    dir: PathBuf,
+    quality: Quality,
+    extension: Extension,
+    index: Mutex<HashMap<String, IndexEntry>>,
+    log: Mutex<File>,
+}
+
+impl<'t> ImageMirror<'t> {
+    /// Open the mirror in `dir`, created if missing, to download high quality WebP images.
+    ///
+    /// # Errors
+    ///
+    /// If the directory or its index cannot be created or read.
+    pub fn new(tcgdex: &'t Tcgdex, dir: impl AsRef<Path>) -> std::io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(dir.join(OBJECTS))?;
+        let path = dir.join(INDEX);
+        let mut index = HashMap::new();
+        if path.exists() {
+            for line in BufReader::new(File::open(&path)?).lines() {
+                // a line cut by an interruption is ignored: its image is downloaded again.
+                if let Ok(entry) = serde_json::from_str::<IndexEntry>(&line?) {
+                    index.insert(entry.url.clone(), entry);
+                }
+            }
+        }
+        let log = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            tcgdex,
+            dir,
+            quality: Quality::High,
+            extension: Extension::Webp,
+            index: Mutex::new(index),
+            log: Mutex::new(log),
+        })
+    }
+
+    /// Set the quality of card images.
+    #[must_use]
+    pub fn with_quality(mut self, quality: Quality) -> Self {
+        self.quality = quality;
+        self
+    }
+
+    /// Set the format of images.
+    #[must_use]
+    pub fn with_extension(mut self, extension: Extension) -> Self {
+        self.extension = extension;
+        self
+    }
+
+    /// Get the file of the image at `url`, or None if it is not in the mirror.
+    #[must_use]
+    pub fn path(&self, url: &str) -> Option<PathBuf> {
+        let index = self.index.lock().unwrap_or_else(PoisonError::into_inner);
+        index.get(url).map(|entry| self.object_path(entry))
+    }
+
+    /// Download the image at `url`, unless it is already in the mirror. Returns its file.
+    ///
+    /// # Errors
+    ///
+    /// If the image cannot be downloaded or written, or if its size is not the announced one.
+    pub fn download_image(&self, url: &str) -> Result<PathBuf, MirrorError> {
+        self.fetch(url).map(|(path, _)| path)
+    }
+
+    /// Download the image of `card`. Returns its file.
+    ///
+    /// # Errors
+    ///
+    /// If the card has no image, or if the image cannot be downloaded.
+    pub fn download_card(&self, card: &Card) -> Result<PathBuf, MirrorError> {
+        let image = card
+            .image_asset()
+            .ok_or_else(|| MirrorError::MissingImage(card.id.clone()))?;
+        self.download_image(&image.url(self.quality, self.extension))
+    }
+
+    /// Download the logo and the symbol of `set`, and the images of its cards.
+    #[must_use]
+    pub fn download_set(&self, set: &Set) -> MirrorReport {
+        let mut images = vec![
+            (
+                format!("{} logo", set.id),
+                set.logo_asset().map(|logo| logo.url(self.extension)),
+            ),
+            (
+                format!("{} symbol", set.id),
+                set.symbol_asset().map(|symbol| symbol.url(self.extension)),
+            ),
+        ];
+        images.extend(set.cards.iter().map(|card| {
+            let url = card
+                .image_asset()
+                .map(|image| image.url(self.quality, self.extension));
+            (card.id.clone(), url)
+        }));
+        self.download_all(images)
+    }
+
+    /// Download all images of `snapshot`: serie logos, set logos and symbols, and card images, in each langage.
+    #[must_use]
+    pub fn download_snapshot(&self, snapshot: &Snapshot) -> MirrorReport {
+        let mut images = Vec::new();
+        for lang in snapshot.langs() {
+            let Some(data) = snapshot.lang(lang) else {
+                continue;
+            };
+            let image = |object: &Value, field: &str| -> (String, Option<String>) {
+                let id = object["id"].as_str().unwrap_or_default();
+                let name = format!("{lang} {id} {field}");
+                let url = object[field].as_str().and_then(Image::new);
+                (name, url.map(|image| image.url(self.extension)))
+            };
+            images.extend(data.series.iter().map(|serie| image(serie, "logo")));
+            for set in &data.sets {
+                images.push(image(set, "logo"));
+                images.push(image(set, "symbol"));
+            }
+            images.extend(data.cards.iter().map(|card| {
+                let id = card["id"].as_str().unwrap_or_default();
+                let url = card["image"]
+                    .as_str()
+                    .and_then(CardImage::new)
+                    .map(|image| image.url(self.quality, self.extension));
+                (format!("{lang} {id}"), url)
+            }));
+        }
+        self.download_all(images)
+    }
+
+    /// Download `images` (object name and image URL), a few at once.
+    fn download_all(&self, images: Vec<(String, Option<String>)>) -> MirrorReport {
+        let mut report = MirrorReport::default();
+        let mut urls = Vec::new();
+        for (name, url) in images {
+            match url {
+                Some(url) => urls.push(url),
+                None => report.missing.push(name),
+            }
+        }
+        let urls = crate::unique_ids(&urls);
+        let results =
+            crate::map_concurrently(&urls, |url| self.fetch(url).map(|(_, fetched)| fetched));
+        for (url, result) in urls.into_iter().zip(results) {
+            match result {
+                Ok(true) => report.downloaded.push(url),
+                Ok(false) => report.skipped.push(url),
+                Err(error) => report.failed.push((url, error)),
+            }
+        }
+        report
+    }
+
+    /// Get the image at `url` in the mirror, downloading it if needed. Returns its file, and true if downloaded.
+    fn fetch(&self, url: &str) -> Result<(PathBuf, bool), MirrorError> {
+        if let Some(path) = self.existing(url) {
+            return Ok((path, false));
+        }
+        let (bytes, expected) = self.tcgdex.download(url)?;
+        let size = bytes.len() as u64;
+        if let Some(expected) = expected.filter(|expected| *expected != size) {
+            return Err(MirrorError::SizeMismatch {
+                url: url.to_string(),
+                expected,
+                actual: size,
+            });
+        }
+        let extension = url.rsplit('.').next().unwrap_or_default();
+        let entry = IndexEntry {
+            url: url.to_string(),
+            hash: format!("{:016x}.{extension}", fnv1a(&bytes)),
+            size,
+        };
+        let path = self.object_path(&entry);
+        // the same image may be stored already, for another URL.
+        if !has_size(&path, size) {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            // another worker may have written the same image meanwhile, for another URL.
+            if let Err(error) = crate::write_atomically(&path, &bytes) {
+                if !has_size(&path, size) {
+                    return Err(error.into());
+                }
+            }
+        }
+        let line = serde_json::to_string(&entry).map_err(std::io::Error::other)?;
+        {
+            let mut log = self.log.lock().unwrap_or_else(PoisonError::into_inner);
+            writeln!(log, "{line}")?;
+            log.flush()?;
+        }
+        self.index
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(url.to_string(), entry);
+        Ok((path, true))
+    }
+
+    /// Get the file of the image at `url`, if it is in the mirror with its expected size.
+    fn existing(&self, url: &str) -> Option<PathBuf> {
+        let index = self.index.lock().unwrap_or_else(PoisonError::into_inner);
+        let entry = index.get(url)?;
+        let path = self.object_path(entry);
+        has_size(&path, entry.size).then_some(path)
+    }
+
+    /// Get the file of the image of `entry`.
+    fn object_path(&self, entry: &IndexEntry) -> PathBuf {
+        self.dir
+            .join(OBJECTS)
+            .join(&entry.hash[..2])
+            .join(&entry.hash)
+    }
+}
+
+/// Returns true if the file at `path` exists with `size` bytes.
+fn has_size(path: &Path, size: u64) -> bool {
+    std::fs::metadata(path).is_ok_and(|metadata| metadata.len() == size)
+}
@@ -0,0 +1,259 @@
+//! Answer requests from a [`Snapshot`] instead of the network.
+//!
+//! Requests are answered like TCGdex API does: filters, sorting and pagination are applied
+//! on full objects, then lists are reduced to brief objects.
+
+use crate::filter::{percent_decode, Operator};
+use crate::snapshot::{LangSnapshot, Snapshot};
+use crate::Lang;
+use serde_json::{json, Map, Value};
+use std::cmp::Ordering;
+
+/// Default number of items in a page, when only the page is given.
+const DEFAULT_ITEMS_PER_PAGE: usize = 100;
+
+/// Get the response of the API for `url` from `snapshot`.
+pub(crate) fn respond(snapshot: &Snapshot, base_url: &str, url: &str) -> http::Response<String> {
+    let target = url.strip_prefix(base_url).unwrap_or(url);
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let segments: Vec<String> = path.split('/').map(percent_decode).collect();
+
+    let lang = segments.first().and_then(|code| code.parse::<Lang>().ok());
+    let found = lang
+        .and_then(|lang| snapshot.lang(lang))
+        .and_then(|data| find(data, &segments[1..], query));
+    match found {
+        Some(value) => http::Response::new(value.to_string()),
+        None => not_found(path, lang),
+    }
+}
+
+/// Find the object or list at `segments` path (after the langage).
+fn find(data: &LangSnapshot, segments: &[String], query: &str) -> Option<Value> {
+    let (object_name, rest) = segments.split_first()?;
+    let objects = match object_name.as_str() {
+        "cards" => &data.cards,
+        "sets" => &data.sets,
+        "series" => &data.series,
+        name => {
+            return match rest {
+                [] => data.lists.get(name).cloned(),
+                [value] => cards_for(&data.cards, name, value),
+                _ => None,
+            }
+        }
+    };
+    match rest {
+        [] => Some(list(objects, object_name, query)),
+        [id] => objects
+            .iter()
+            .find(|object| object.get("id").and_then(Value::as_str) == Some(id))
+            .cloned(),
+        [set, local_id] if object_name == "sets" => data
+            .cards
+            .iter()
+            .find(|card| {
+                card.pointer("/set/id").and_then(Value::as_str) == Some(set)
+                    && card.get("localId").and_then(Value::as_str) == Some(local_id)
+            })
+            .cloned(),
+        _ => None,
+    }
+}
+
+/// Get the cards with `value` of `object_name` list endpoint, like `types/Fire`.
+fn cards_for(cards: &[Value], object_name: &str, value: &str) -> Option<Value> {
+    let field = match object_name {
+        "types" => "types",
+        "categories" => "category",
+        "hp" => "hp",
+        "illustrators" => "illustrator",
+        "rarities" => "rarity",
+        "retreats" => "retreat",
+        _ => return None,
+    };
+    let matches = |field: &Value| match field {
+        Value::String(text) => text.eq_ignore_ascii_case(value),
+        Value::Number(number) => number.to_string() == value,
+        _ => false,
+    };
+    let cards: Vec<Value> = cards
+        .iter()
+        .filter(|card| match card.get(field) {
+            Some(Value::Array(items)) => items.iter().any(matches),
+            Some(field) => matches(field),
+            None => false,
+        })
+        .map(|card| brief(card, "cards"))
+        .collect();
+    // like the API, an unknown value is not found.
+    (!cards.is_empty()).then(|| json!({ "name": value, "cards": cards }))
+}
+
+/// Get the brief list of `objects` matching `query` string.
+fn list(objects: &[Value], object_name: &str, query: &str) -> Value {
+    let mut filters = Vec::new();
+    let mut sorting: Vec<(String, bool)> = Vec::new();
+    let mut page = None;
+    let mut items_per_page = None;
+    for parameter in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+        match key {
+            "pagination:page" => page = value.parse::<usize>().ok(),
+            "pagination:itemsPerPage" => items_per_page = value.parse::<usize>().ok(),
+            "sort:field" => sorting.push((percent_decode(value), false)),
+            "sort:order" => {
+                if let Some((_, descending)) = sorting.last_mut() {
+                    *descending = value.eq_ignore_ascii_case("DESC");
+                }
+            }
+            field => filters.push((percent_decode(field), value)),
+        }
+    }
+
+    let mut items: Vec<&Value> = objects
+        .iter()
+        .filter(|object| {
+            filters
+                .iter()
+                .all(|(field, value)| matches(object, field, value))
+        })
+        .collect();
+    if !sorting.is_empty() {
+        items.sort_by(|a, b| {
+            sorting
+                .iter()
+                .map(|(field, descending)| {
+                    let ordering = compare(lookup(a, field), lookup(b, field));
+                    if *descending {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+    if page.is_some() || items_per_page.is_some() {
+        let items_per_page = items_per_page.unwrap_or(DEFAULT_ITEMS_PER_PAGE);
+        let skipped = page.unwrap_or(1).max(1).saturating_sub(1) * items_per_page;
+        items = items
+            .into_iter()
+            .skip(skipped)
+            .take(items_per_page)
+            .collect();
+    }
+    Value::Array(
+        items
+            .into_iter()
+            .map(|item| brief(item, object_name))
+            .collect(),
+    )
+}
+
+/// Get the value at `path` (like `set.id`) in `object`.
+fn lookup<'v>(object: &'v Value, path: &str) -> Option<&'v Value> {
+    path.split('.')
+        .try_fold(object, |value, key| value.get(key))
+        .filter(|value| !value.is_null())
+}
+
+/// Returns true if `object` matches the raw filter `value` (like `gte:60` or `pikachu|raichu`) on `field`.
+fn matches(object: &Value, field: &str, value: &str) -> bool {
+    let (operator, values) = Operator::split(value);
+    let values: Vec<String> = values.split('|').map(percent_decode).collect();
+    let value = lookup(object, field);
+    match operator {
+        Operator::Null => value.is_none(),
+        Operator::NotNull => value.is_some(),
+        Operator::NotLike => !values.iter().any(|v| test(value, v, Operator::Like)),
+        Operator::Neq => !values.iter().any(|v| test(value, v, Operator::Eq)),
+        operator => values.iter().any(|v| test(value, v, operator)),
+    }
+}
+
+/// Compare `value` to `expected` with a positive `operator`. For a list, one item must match.
+fn test(value: Option<&Value>, expected: &str, operator: Operator) -> bool {
+    let Some(value) = value else {
+        return false;
+    };
+    if let Value::Array(items) = value {
+        return items
+            .iter()
+            .any(|item| test(Some(item), expected, operator));
+    }
+    let text = text(value);
+    match operator {
+        Operator::Like if value.is_string() => {
+            text.to_lowercase().contains(&expected.to_lowercase())
+        }
+        Operator::Like => text.eq_ignore_ascii_case(expected),
+        Operator::Eq => text == expected,
+        Operator::Gt => compare_text(&text, expected).is_gt(),
+        Operator::Gte => compare_text(&text, expected).is_ge(),
+        Operator::Lt => compare_text(&text, expected).is_lt(),
+        Operator::Lte => compare_text(&text, expected).is_le(),
+        _ => false,
+    }
+}
+
+/// Get a scalar value as text, without quotes for strings.
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Compare numerically if both are numbers, else as text (dates yyyy-mm-dd included).
+fn compare_text(a: &str, b: &str) -> Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}
+
+/// Compare sorting values, missing ones last.
+fn compare(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => compare_text(&text(a), &text(b)),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Reduce a full object to the brief object returned in lists.
+fn brief(object: &Value, object_name: &str) -> Value {
+    let keys: &[&str] = match object_name {
+        "cards" => &["id", "localId", "name", "image"],
+        "sets" => &["id", "name", "logo", "symbol", "cardCount"],
+        _ => &["id", "name", "logo"],
+    };
+    let mut brief = Map::new();
+    for &key in keys {
+        if let Some(value) = object.get(key) {
+            brief.insert(key.to_string(), value.clone());
+        }
+    }
+    if let Some(Value::Object(count)) = brief.get_mut("cardCount") {
+        count.retain(|key, _| key == "total" || key == "official");
+    }
+    Value::Object(brief)
+}
+
+/// Get the TCGdex error response for a missing resource.
+fn not_found(path: &str, lang: Option<Lang>) -> http::Response<String> {
+    let body = json!({
+        "type": "https://tcgdex.dev/errors/not-found",
+        "title": "The resource you are trying to reach does not exists",
+        "status": 404,
+        "endpoint": format!("/{path}"),
+        "method": "GET",
+        "lang": lang.map(Lang::code).unwrap_or_default(),
+    });
+    let mut response = http::Response::new(body.to_string());
+    *response.status_mut() = http::StatusCode::NOT_FOUND;
+    response
+}
@@ -0,0 +1,164 @@
+//! Walk through all pages of a list using [`Paginator`] (or an asynchronous stream with `async` feature).
+//!
+//! Pages are requested lazily, one at a time, until a page is empty or incomplete.
+
+use crate::errors::{self, ApiError};
+use crate::is_empty::IsEmpty;
+use crate::query::Query;
+use crate::Tcgdex;
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+
+/// Lazy iterator over all items of a list, requesting pages of a fixed size.
+///
+/// Get it with `paginate` function of cards, sets or series interfaces.
+///
+/// If a request fails, the error is returned and the iteration ends.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use tcgdex_api::{Query, Tcgdex};
+/// let tcgdex = Tcgdex::new();
+/// let filter = Query::new().with_filtering(vec!["name=furret"]);
+/// for card in tcgdex.cards().paginate(Some(&filter), 50) {
+///     println!("card = {:?}", card.unwrap());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Paginator<'a, T> {
+    tcgdex: &'a Tcgdex,
+    object_name: &'static str,
+    state: State<T>,
+}
+
+/// Progress of a pagination.
+#[derive(Debug)]
+struct State<T> {
+    query: Query,
+    items_per_page: u16,
+    next_page: u32,
+    buffer: VecDeque<T>,
+    done: bool,
+}
+
+impl<T> State<T> {
+    fn new(query: Option<&Query>, items_per_page: u16) -> Self {
+        Self {
+            query: query.cloned().unwrap_or_default(),
+            items_per_page: items_per_page.max(1),
+            next_page: 1,
+            buffer: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Get the query of the next page.
+    fn next_query(&self) -> errors::Result<Query> {
+        let query = self
+            .query
+            .clone()
+            .with_pagination(self.next_page, self.items_per_page);
+        query.validate()?;
+        Ok(query)
+    }
+
+    /// Store a page result. Returns an error to give to the caller, if any.
+    fn push_page(&mut self, page: errors::Result<Vec<T>>) -> Option<ApiError> {
+        match page {
+            Ok(items) => {
+                if items.len() < usize::from(self.items_per_page) {
+                    self.done = true;
+                }
+                self.next_page += 1;
+                self.buffer.extend(items);
+                None
+            }
+            // an empty page is the end of the list.
+            Err(ApiError::EmptyResponse) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                Some(error)
+            }
+        }
+    }
+}
+
+impl<'a, T> Paginator<'a, T> {
+    pub(crate) fn new(
+        tcgdex: &'a Tcgdex,
+        object_name: &'static str,
+        query: Option<&Query>,
+        items_per_page: u16,
+    ) -> Self {
+        Self {
+            tcgdex,
+            object_name,
+            state: State::new(query, items_per_page),
+        }
+    }
+}
+
+impl<T> Iterator for Paginator<'_, T>
+where
+    T: DeserializeOwned,
+    Vec<T>: IsEmpty,
+{
+    type Item = errors::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.state.buffer.pop_front() {
+                return Some(Ok(item));
+            }
+            if self.state.done {
+                return None;
+            }
+            let page = self.state.next_query().and_then(|query| {
+                let url = self.tcgdex.url(self.object_name, Some(&query));
+                self.tcgdex.get::<Vec<T>>(&url).and_then(errors::non_empty)
+            });
+            if let Some(error) = self.state.push_page(page) {
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+/// Asynchronous counterpart of [`Paginator`]: a stream over all items of a list.
+#[cfg(feature = "async")]
+pub(crate) fn stream<'a, T>(
+    tcgdex: &'a crate::AsyncTcgdex,
+    object_name: &'static str,
+    query: Option<&Query>,
+    items_per_page: u16,
+) -> impl futures_util::Stream<Item = errors::Result<T>> + 'a
+where
+    T: DeserializeOwned + 'a,
+    Vec<T>: IsEmpty,
+{
+    let state = State::new(query, items_per_page);
+    futures_util::stream::unfold(state, move |mut state| async move {
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                return Some((Ok(item), state));
+            }
+            if state.done {
+                return None;
+            }
+            let page = match state.next_query() {
+                Ok(query) => tcgdex
+                    .get::<Vec<T>>(&tcgdex.url(object_name, Some(&query)))
+                    .await
+                    .and_then(errors::non_empty),
+                Err(error) => Err(error),
+            };
+            if let Some(error) = state.push_page(page) {
+                return Some((Err(error), state));
+            }
+        }
+    })
+}
@@ -0,0 +1,106 @@
+//! Request handling shared by [`Tcgdex`](crate::Tcgdex) and `AsyncTcgdex`.
+//!
+//! A [`Pipeline`] decides everything about a request but sending it and waiting: clients only
+//! send requests and sleep, blocking or not, with the delays it gives.
+
+use crate::cache::{self, CacheKey, CacheLayer, CachedResponse, Lookup};
+use crate::retry::{Attempt, RateLimiter, RetryPolicy};
+use crate::snapshot::Snapshot;
+use crate::{cache_key, http_response, offline, text, Lang};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Snapshot, cache, retry policy and rate limiter of a client, each optional.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Pipeline {
+    pub(crate) cache: Option<CacheLayer>,
+    pub(crate) snapshot: Option<Arc<Snapshot>>,
+    pub(crate) retry: Option<RetryPolicy>,
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+/// How to answer a GET request, decided by [`Pipeline::start`].
+pub(crate) enum Start {
+    /// The response is known without sending the request.
+    Done(http::Response<String>),
+
+    /// The request must be sent, then its response given to [`Pipeline::finish`].
+    Send(Pending),
+}
+
+/// A request to send, with what is needed to store its response.
+#[derive(Default)]
+pub(crate) struct Pending {
+    /// Headers to add to the request, to revalidate a stale response.
+    pub(crate) headers: HeaderMap,
+    key: Option<CacheKey>,
+    stale: Option<CachedResponse>,
+}
+
+impl Pipeline {
+    /// Answer a GET request to `url` from the snapshot or a fresh cached response, or get the request to send.
+    pub(crate) fn start(&self, base_url: &str, lang: Lang, url: &str) -> Start {
+        if let Some(snapshot) = &self.snapshot {
+            return Start::Done(offline::respond(snapshot, base_url, url));
+        }
+        let Some(cache) = &self.cache else {
+            return Start::Send(Pending::default());
+        };
+        let key = cache_key(base_url, lang, url);
+        match cache.lookup(&key) {
+            Lookup::Fresh(cached) => Start::Done(cache::replay(cached.body)),
+            Lookup::Stale(cached) => Start::Send(Pending {
+                headers: cache::conditional_headers(&cached),
+                key: Some(key),
+                stale: Some(cached),
+            }),
+            Lookup::Missing => Start::Send(Pending {
+                key: Some(key),
+                ..Pending::default()
+            }),
+        }
+    }
+
+    /// Get the response to `pending` request from the one received, storing or revalidating it in the cache.
+    pub(crate) fn finish(
+        &self,
+        pending: Pending,
+        response: http::Response<Vec<u8>>,
+    ) -> http::Response<String> {
+        let (parts, body) = response.into_parts();
+        let (Some(cache), Some(key)) = (&self.cache, pending.key) else {
+            return http_response(parts.status, parts.headers, text(body));
+        };
+        let body = match pending.stale {
+            Some(cached) if parts.status == StatusCode::NOT_MODIFIED => {
+                cache.revalidated(&key, cached)
+            }
+            _ if parts.status.is_success() => cache.store(&key, &parts.headers, text(body)),
+            _ => return http_response(parts.status, parts.headers, text(body)),
+        };
+        cache::replay(body)
+    }
+
+    /// Get the delay before sending again a request after its `attempt` (from 1) gave `result`, or None to stop.
+    pub(crate) fn retry_delay(
+        &self,
+        attempt: u32,
+        result: &reqwest::Result<http::Response<Vec<u8>>>,
+    ) -> Option<Duration> {
+        let policy = self.retry.as_ref()?;
+        match result {
+            Ok(response) => policy.retry_delay(
+                attempt,
+                &Attempt::Response(response.status(), response.headers().get(RETRY_AFTER)),
+            ),
+            Err(error) => policy.retry_delay(attempt, &Attempt::Error(error)),
+        }
+    }
+
+    /// Reserve a slot for a request from the rate limiter, if any, and get the time to wait before sending it.
+    pub(crate) fn throttle_delay(&self) -> Option<Duration> {
+        self.rate_limiter.as_ref().map(|limiter| limiter.reserve())
+    }
+}
@@ -1,14 +1,15 @@
 //! To construct a query.
 
-use crate::errors::TcgdexError;
-use serde::Deserialize;
+use crate::errors::QueryError;
+use crate::filter::{self, Filter};
+use crate::Lang;
 use std::fmt::{Display, Formatter};
 
-/// Constant part of the URL for queries.
+/// Default base URL for queries.
 pub(crate) const URL_BASE: &str = "https://api.tcgdex.net/v2/";
 
 /// Used to set sorting order.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Order {
     /// Ascending sorting.
     ASC,
@@ -27,28 +28,32 @@ impl Display for Order {
 ///
 /// Check [TCGdex API reference](https://tcgdex.dev) for details about query parameters.
 ///
-/// If id is already set, set filtering, sorting or pagination have no effect.
+/// Builder methods accumulate: filters and sorting fields are added to the ones already set.
 ///
-/// If at least one of filtering, sorting and pagination is already set, set id have no effect.
+/// An id cannot be combined with filtering, sorting or pagination. Such a query is not dropped,
+/// it is reported as an [`ApiError::InvalidQuery`](crate::errors::ApiError::InvalidQuery) error when used (see [`Query::validate`]).
 ///
 /// # Example
 ///
 /// ```rust
 /// # use tcgdex_api::query::{Order, Query};
 ///
-/// // to get a filtered card list
-/// let query = Query::new().with_filtering(vec!["hp=100"]).with_sorting("name", &Order::ASC);
+/// // to get a filtered card list, sorted by name then by descending hp
+/// let query = Query::new()
+///     .with_filtering(vec!["hp=100"])
+///     .with_sorting("name", &Order::ASC)
+///     .with_sorting("hp", &Order::DESC);
 ///
 /// // to get a specific card with its id
 /// let query = Query::new().with_id("swsh3-136").to_string();
 /// ```
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Default)]
 pub struct Query {
-    id: String,
-    filtering: String,
-    pagination: String,
-    sorting: String,
+    ids: Vec<String>,
+    filters: Vec<String>,
+    pagination: Option<(u32, u16)>,
+    sorting: Vec<(String, Order)>,
 }
 
 impl Query {
@@ -57,23 +62,24 @@ impl Query {
     #[must_use]
     pub const fn new() -> Self {
         Self {
-            id: String::new(),
-            filtering: String::new(),
-            pagination: String::new(),
-            sorting: String::new(),
+            ids: Vec::new(),
+            filters: Vec::new(),
+            pagination: None,
+            sorting: Vec::new(),
         }
     }
 
     /// Set id to get (for cards and sets).
     #[must_use]
     pub fn with_id(mut self, id: &str) -> Self {
-        if self.filtering.is_empty() && self.sorting.is_empty() && self.pagination.is_empty() {
-            self.id = id.to_string();
-        }
+        self.ids.push(id.to_string());
         self
     }
 
-    /// Set filter to use. More details about filtering [here](https://tcgdex.dev/rest/filtering-sorting-pagination).
+    /// Add filters to use. More details about filtering [here](https://tcgdex.dev/rest/filtering-sorting-pagination).
+    ///
+    /// Values are URL encoded, so they can contain whitespaces or `&`.
+    /// See [`Query::with_filter`] to use typed filters.
     ///
     /// # Argument
     ///
@@ -85,22 +91,38 @@ impl Query {
     /// # use tcgdex_api::query::Query;
     ///
     /// // to get a filtered card list with only pikachu cards with 100 hp
-    /// let query = Query::new().with_filtering(vec!["hp=100", "name=pikachu"]);
+    /// let query = Query::new().with_filtering(vec!["hp=100"]).with_filtering(vec!["name=pikachu"]);
+    /// assert_eq!(query.to_string(), "hp=100&name=pikachu");
     /// ```
     #[must_use]
     pub fn with_filtering(mut self, filter: Vec<&str>) -> Self {
-        if self.id.is_empty() {
-            let mut fixed_filter: Vec<&str> = Vec::new();
-            for item in filter {
-                fixed_filter.push(item.split_whitespace().collect::<Vec<&str>>()[0]);
-            }
-            self.filtering = fixed_filter.join("&");
-        }
+        self.filters
+            .extend(filter.into_iter().map(filter::encode_raw));
+        self
+    }
+
+    /// Add a typed filter to already set ones. See [`Filter`].
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// # use tcgdex_api::filter::{Field, Filter};
+    /// # use tcgdex_api::query::Query;
+    ///
+    /// // to get sets with more than 200 cards
+    /// let query = Query::new().with_filter(Filter::field(Field::CARD_COUNT_TOTAL).gt(200));
+    /// assert_eq!(query.to_string(), "cardCount.total=gt:200");
+    /// ```
+    #[must_use]
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        self.filters.push(filter.to_string());
         self
     }
 
     /// Set pagination to use. More details about pagination [here](https://tcgdex.dev/rest/filtering-sorting-pagination).
     ///
+    /// A query has a single page: it replaces pagination set before.
+    ///
     /// # Arguments
     ///
     /// `page` - Output page to read.
@@ -115,15 +137,14 @@ impl Query {
     /// let query = Query::new().with_pagination(3, 5);
     /// ```
     #[must_use]
-    pub fn with_pagination(mut self, page: u8, items_per_page: u16) -> Self {
-        if self.id.is_empty() {
-            self.pagination =
-                format!("pagination:page={page}&pagination:itemsPerPage={items_per_page}");
-        }
+    pub fn with_pagination(mut self, page: u32, items_per_page: u16) -> Self {
+        self.pagination = Some((page, items_per_page));
         self
     }
 
-    /// Set sorting to use. More details about sorting [here](https://tcgdex.dev/rest/filtering-sorting-pagination).
+    /// Add a sorting field. More details about sorting [here](https://tcgdex.dev/rest/filtering-sorting-pagination).
+    ///
+    /// Fields are used in the order they are added, each one with its own order.
     ///
     /// # Arguments
     ///
@@ -135,40 +156,98 @@ impl Query {
     ///```rust
     /// # use tcgdex_api::query::{Query, Order};
     ///
-    /// // to get a card list sorted by ascending hp
-    /// let query = Query::new().with_sorting("hp", &Order::ASC);
+    /// // to get a card list sorted by ascending hp, then by descending name
+    /// let query = Query::new().with_sorting("hp", &Order::ASC).with_sorting("name", &Order::DESC);
     /// ```
     #[must_use]
     pub fn with_sorting(mut self, field: &str, order: &Order) -> Self {
-        if self.id.is_empty() {
-            self.sorting = format!("sort:field={field}&sort:order={order}");
-        }
+        self.sorting.push((field.to_string(), *order));
         self
     }
+
+    /// Check that the query parameters can be used together.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`QueryError`] if an id is combined with other parameters, if several ids are set,
+    /// or if a field is sorted in both orders.
+    pub fn validate(&self) -> Result<(), QueryError> {
+        if let Some(other) = self.ids.iter().find(|id| **id != self.ids[0]) {
+            return Err(QueryError::ConflictingIds(
+                self.ids[0].clone(),
+                other.clone(),
+            ));
+        }
+        if !self.ids.is_empty() {
+            if !self.filters.is_empty() {
+                return Err(QueryError::IdWithFiltering);
+            }
+            if !self.sorting.is_empty() {
+                return Err(QueryError::IdWithSorting);
+            }
+            if self.pagination.is_some() {
+                return Err(QueryError::IdWithPagination);
+            }
+        }
+        for (i, (field, order)) in self.sorting.iter().enumerate() {
+            if self.sorting[..i]
+                .iter()
+                .any(|(f, o)| f == field && o != order)
+            {
+                return Err(QueryError::ConflictingSortOrders(field.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the query string part (filtering, pagination and sorting).
+    fn parameters(&self) -> Vec<String> {
+        let mut parameters = self.filters.clone();
+        if let Some((page, items_per_page)) = self.pagination {
+            parameters.push(format!(
+                "pagination:page={page}&pagination:itemsPerPage={items_per_page}"
+            ));
+        }
+        let mut sorted: Vec<&str> = Vec::new();
+        for (field, order) in &self.sorting {
+            if !sorted.contains(&field.as_str()) {
+                sorted.push(field);
+                parameters.push(format!("sort:field={field}&sort:order={order}"));
+            }
+        }
+        parameters
+    }
 }
+
 impl Display for Query {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let query: Vec<String> = [&self.id, &self.filtering, &self.pagination, &self.sorting]
-            .into_iter()
-            .filter(|v| !v.is_empty())
-            .map(ToString::to_string)
-            .collect();
+        let mut query: Vec<String> = self.ids.first().cloned().into_iter().collect();
+        query.extend(self.parameters());
         write!(f, "{}", query.join("&"))
     }
 }
 
-impl Default for Query {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-/// Request response can be a T data structure in case of success
+/// Build the URL to reach `object_name` endpoint of the API at `base_url` in `lang` langage.
 ///
-/// or can be an error structure in some cases of failure.
-#[derive(Deserialize, Debug)]
-#[serde(untagged)]
-pub(crate) enum Response<T> {
-    Error(TcgdexError),
-    Data(T),
+/// If a query is given, it is appended as path (id) or as query string (filtering, sorting or pagination).
+pub(crate) fn endpoint_url(
+    base_url: &str,
+    lang: Lang,
+    object_name: &str,
+    query: Option<&Query>,
+) -> String {
+    let url = format!("{base_url}{}/{object_name}", lang.code());
+    let Some(query) = query else {
+        return url;
+    };
+    if let Some(id) = query.ids.first() {
+        return format!("{url}/{}", filter::percent_encode(id));
+    }
+    let parameters = query.parameters();
+    // no trailing separator without query.
+    if parameters.is_empty() {
+        url
+    } else {
+        format!("{url}?{}", parameters.join("&"))
+    }
 }
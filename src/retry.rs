@@ -0,0 +1,246 @@
+//! Retry failed requests with a [`RetryPolicy`] and limit the request rate with a [`RateLimiter`].
+//!
+//! Both are set with [`TcgdexBuilder`](crate::TcgdexBuilder) and apply to every request of every endpoint.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use tcgdex_api::retry::{RateLimiter, RetryPolicy};
+//! # use tcgdex_api::TcgdexBuilder;
+//! # use std::time::Duration;
+//! let tcgdex = TcgdexBuilder::new()
+//!     .with_retry(RetryPolicy::new().with_max_attempts(5))
+//!     .with_rate_limit(RateLimiter::new(10, Duration::from_secs(1)))
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use reqwest::header::HeaderValue;
+use reqwest::StatusCode;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// When and how often a failed request is sent again.
+///
+/// A request is retried when the API answers one of the retry status codes
+/// (by default 429, 500, 502, 503 and 504), or when the connection fails, is reset or times out.
+///
+/// Delay between attempts grows exponentially, with a random part (jitter) so clients
+/// do not retry all at once. A `Retry-After` header sent by the API is used instead, if any.
+/// When it asks to wait longer than the maximum backoff, the request is not retried and fails
+/// with [`ApiError::RateLimited`](crate::errors::ApiError::RateLimited) for a 429 status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    statuses: Vec<StatusCode>,
+    respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy with 3 attempts and a backoff from 500 ms to 30 s.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            respect_retry_after: true,
+        }
+    }
+
+    /// Set the maximum number of attempts of a request, first one included (at least one).
+    #[must_use]
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry, doubled at each retry up to `max`.
+    ///
+    /// A `Retry-After` header asking to wait longer than `max` stops the retries.
+    #[must_use]
+    pub const fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Set the HTTP status codes that cause a retry. Invalid codes are ignored.
+    #[must_use]
+    pub fn with_retry_statuses(mut self, statuses: &[u16]) -> Self {
+        self.statuses = statuses
+            .iter()
+            .filter_map(|status| StatusCode::from_u16(*status).ok())
+            .collect();
+        self
+    }
+
+    /// Set whether a `Retry-After` header replaces the computed delay. Default is true.
+    #[must_use]
+    pub const fn with_respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    /// Get the delay before sending again a request after its `attempt` (from 1), or None to stop.
+    pub(crate) fn retry_delay(&self, attempt: u32, outcome: &Attempt<'_>) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        let retry_after = match outcome {
+            Attempt::Response(status, retry_after) if self.statuses.contains(status) => {
+                *retry_after
+            }
+            // a reset connection fails the request or the read of its body, reported as a decode error
+            // by blocking clients.
+            Attempt::Error(error)
+                if error.is_timeout()
+                    || error.is_connect()
+                    || error.is_request()
+                    || error.is_body()
+                    || error.is_decode() =>
+            {
+                None
+            }
+            _ => return None,
+        };
+        match retry_after.and_then(parse_retry_after) {
+            Some(delay) if self.respect_retry_after => (delay <= self.max_backoff).then_some(delay),
+            _ => Some(self.backoff(attempt)),
+        }
+    }
+
+    /// Get the exponential backoff after `attempt`, between half and all of it.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        backoff / 2 + backoff.mul_f64(jitter() / 2.0)
+    }
+}
+
+/// Result of a request attempt, as seen by a [`RetryPolicy`].
+pub(crate) enum Attempt<'a> {
+    /// The API answered with this status and this `Retry-After` header.
+    Response(StatusCode, Option<&'a HeaderValue>),
+
+    /// The request failed.
+    Error(&'a reqwest::Error),
+}
+
+/// Limit the number of requests sent during a period.
+///
+/// Up to `requests` are sent at once, then requests are spaced evenly. Clients sharing a limiter
+/// (built by the same builder or its clones) share the limit.
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    burst: Duration,
+    start: Instant,
+    // theoretical arrival time of the next request, since start.
+    next: Mutex<Duration>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `requests` (at least one) per `period`.
+    #[must_use]
+    pub fn new(requests: u32, period: Duration) -> Self {
+        let requests = requests.max(1);
+        let interval = period / requests;
+        Self {
+            interval,
+            burst: interval * (requests - 1),
+            start: Instant::now(),
+            next: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Reserve a slot for a request, and get the time to wait before sending it.
+    pub(crate) fn reserve(&self) -> Duration {
+        let mut next = self.next.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = self.start.elapsed();
+        let slot = (*next).max(now);
+        *next = slot + self.interval;
+        slot.saturating_sub(self.burst).saturating_sub(now)
+    }
+}
+
+/// Parse a `Retry-After` header: a number of seconds or an HTTP date.
+pub(crate) fn parse_retry_after(value: &HeaderValue) -> Option<Duration> {
+    let value = value.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = UNIX_EPOCH + Duration::from_secs(parse_http_date(value)?);
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Parse an HTTP date like `Wed, 21 Oct 2015 07:28:00 GMT` into seconds since UNIX epoch.
+fn parse_http_date(value: &str) -> Option<u64> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let mut parts = value.split_whitespace().skip(1);
+    let day: u64 = parts.next()?.parse().ok()?;
+    let month = MONTHS.iter().position(|m| Some(*m) == parts.next())?;
+    let year: u64 = parts.next()?.parse().ok()?;
+    let mut time = parts.next()?.split(':').map(str::parse::<u64>);
+    let (hours, minutes, seconds) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+    // 60 seconds is a leap second.
+    if !(1..=31).contains(&day) || hours > 23 || minutes > 59 || seconds > 60 {
+        return None;
+    }
+
+    // civil date to days, from Howard Hinnant's algorithm.
+    let month = u64::try_from(month).ok()? + 1;
+    let year = if month <= 2 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
+    let era = year / 400;
+    let yoe = year % 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day.checked_sub(1)?;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = (era * 146_097 + doe).checked_sub(719_468)?;
+    Some(days * 86_400 + hours * 3600 + minutes * 60 + seconds)
+}
+
+/// Get a pseudo random number in `[0, 1)`. Good enough to spread retries.
+fn jitter() -> f64 {
+    static STATE: AtomicU64 = AtomicU64::new(0);
+    let seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos();
+    let mut x = STATE.fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed) ^ u64::from(seed);
+    // xorshift64*
+    x ^= x >> 12;
+    x ^= x << 25;
+    x ^= x >> 27;
+    let random = x.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11;
+    // 53 bits fit exactly in a f64.
+    random as f64 / (1_u64 << 53) as f64
+}
@@ -0,0 +1,534 @@
+//! Download TCGdex data for offline use with [`Snapshot`].
+//!
+//! A snapshot contains all series, sets and cards of chosen langages, plus the types, categories,
+//! hp, illustrators, rarities, retreats, stages, suffixes, trainer types, energy types, regulation marks,
+//! variants and dex ids lists. It is saved in a versioned JSON file.
+//!
+//! A client built with [`TcgdexBuilder::with_snapshot`](crate::TcgdexBuilder::with_snapshot) answers
+//! all requests from the snapshot, including [`Query`] filters, sorting and pagination, without network.
+//!
+//! [`Snapshot::sync`] refreshes a snapshot, requesting only new sets and changed cards.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use tcgdex_api::snapshot::Snapshot;
+//! # use tcgdex_api::{Lang, Query, Tcgdex, TcgdexBuilder};
+//! # use tcgdex_api::endpoints::cards::Card;
+//! // before the tournament
+//! let snapshot = Snapshot::download(&Tcgdex::new(), &[Lang::EN, Lang::FR]).unwrap();
+//! snapshot.save("tcgdex.json").unwrap();
+//!
+//! // in the venue
+//! let tcgdex = TcgdexBuilder::new()
+//!     .with_snapshot(Snapshot::load("tcgdex.json").unwrap())
+//!     .build()
+//!     .unwrap();
+//! let card: Card = tcgdex.cards().fetch(Some(&Query::new().with_id("swsh3-136"))).unwrap();
+//! ```
+
+use crate::errors::{self, ApiError, SnapshotError};
+use crate::filter::{Field, Filter};
+use crate::query::{endpoint_url, Query};
+use crate::{Lang, Tcgdex};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Version of the snapshot file format written by this library.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Lists endpoints stored in a snapshot.
+const LISTS: [&str; 13] = [
+    "types",
+    "categories",
+    "hp",
+    "illustrators",
+    "rarities",
+    "retreats",
+    "stages",
+    "suffixes",
+    "trainer-types",
+    "energy-types",
+    "regulation-marks",
+    "variants",
+    "dex-ids",
+];
+
+/// Margin removed from the last sync time, in seconds.
+const SYNC_MARGIN: u64 = 86_400;
+
+/// A local copy of TCGdex data in one or several langages.
+///
+/// Objects are stored as returned by the API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    version: u32,
+    created_at: u64,
+    #[serde(default)]
+    synced_at: u64,
+    langs: BTreeMap<String, LangSnapshot>,
+}
+
+/// Data of one langage.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct LangSnapshot {
+    pub(crate) series: Vec<Value>,
+    pub(crate) sets: Vec<Value>,
+    pub(crate) cards: Vec<Value>,
+    pub(crate) lists: BTreeMap<String, Value>,
+}
+
+/// Ids of objects added, changed or removed by a [`Snapshot::sync`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Changes {
+    /// Objects not in the snapshot before.
+    pub added: Vec<String>,
+
+    /// Objects with different data.
+    pub changed: Vec<String>,
+
+    /// Objects not available anymore.
+    pub removed: Vec<String>,
+}
+
+impl Changes {
+    /// Returns true if nothing moved.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Changes done by a [`Snapshot::sync`] in one langage.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LangDiff {
+    /// Series changes. Series data are not compared: only added and removed series are reported.
+    pub series: Changes,
+
+    /// Sets changes.
+    pub sets: Changes,
+
+    /// Cards changes.
+    pub cards: Changes,
+}
+
+impl LangDiff {
+    /// Returns true if nothing moved.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.series.is_empty() && self.sets.is_empty() && self.cards.is_empty()
+    }
+}
+
+/// Result of a [`Snapshot::sync`]: changes by langage.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncDiff {
+    /// Changes of each langage of the snapshot.
+    pub langs: HashMap<Lang, LangDiff>,
+}
+
+impl SyncDiff {
+    /// Returns true if nothing moved.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.langs.values().all(LangDiff::is_empty)
+    }
+}
+
+/// Beginning of a snapshot file, read before the whole content to check the version.
+#[derive(Deserialize)]
+struct Header {
+    version: u32,
+}
+
+impl Snapshot {
+    /// Download all data in each langage of `langs` using `tcgdex` client.
+    ///
+    /// Objects listed but missing in a langage are skipped.
+    /// It sends one request per serie, set and card: it takes a while.
+    ///
+    /// # Errors
+    ///
+    /// If a request fails for another reason than a missing object.
+    pub fn download(tcgdex: &Tcgdex, langs: &[Lang]) -> errors::Result<Self> {
+        let mut snapshot = Self {
+            version: SNAPSHOT_VERSION,
+            created_at: now(),
+            synced_at: 0,
+            langs: BTreeMap::new(),
+        };
+        for &lang in langs {
+            let data = LangSnapshot::download(tcgdex, lang)?;
+            snapshot.langs.insert(lang.code().to_string(), data);
+        }
+        Ok(snapshot)
+    }
+
+    /// Write the snapshot in the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        let path = path.as_ref();
+        let content = serde_json::to_vec(self)?;
+        // an interrupted save keeps the previous file.
+        crate::write_atomically(path, &content)?;
+        Ok(())
+    }
+
+    /// Read a snapshot from the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read, is not a snapshot or was written with an unsupported format version.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        let content = std::fs::read(path)?;
+        let header: Header = serde_json::from_slice(&content)?;
+        if header.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(header.version));
+        }
+        Ok(serde_json::from_slice(&content)?)
+    }
+
+    /// Get the format version of the snapshot.
+    #[must_use]
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Get the time when the snapshot was downloaded.
+    #[must_use]
+    pub fn created_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.created_at)
+    }
+
+    /// Get the time of the last successful [`Snapshot::sync`], or of the download if never synced.
+    #[must_use]
+    pub fn synced_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.synced_at.max(self.created_at))
+    }
+
+    /// Update the snapshot with remote data of its langages, using `tcgdex` client.
+    ///
+    /// Only some objects are requested:
+    /// - new series and sets, with all their cards,
+    /// - sets with a different cards count or released since last sync, to find added and removed cards,
+    /// - cards `updated` since last sync.
+    ///
+    /// Series and sets missing from remote lists are removed, with their cards.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tcgdex_api::snapshot::Snapshot;
+    /// # use tcgdex_api::Tcgdex;
+    /// let mut snapshot = Snapshot::load("tcgdex.json").unwrap();
+    /// let diff = snapshot.sync(&Tcgdex::new()).unwrap();
+    /// for (lang, changes) in &diff.langs {
+    ///     println!("{lang}: {} new cards", changes.cards.added.len());
+    /// }
+    /// snapshot.save("tcgdex.json").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If a request fails for another reason than a missing object.
+    /// Changes done before the error are kept: the next sync requests them again.
+    pub fn sync(&mut self, tcgdex: &Tcgdex) -> errors::Result<SyncDiff> {
+        let started = now();
+        // a margin, as remote dates have various time zones.
+        let since = self
+            .synced_at
+            .max(self.created_at)
+            .saturating_sub(SYNC_MARGIN);
+        let mut diff = SyncDiff::default();
+        for (code, data) in &mut self.langs {
+            let Ok(lang) = code.parse::<Lang>() else {
+                continue;
+            };
+            diff.langs.insert(lang, data.sync(tcgdex, lang, since)?);
+        }
+        self.synced_at = started;
+        Ok(diff)
+    }
+
+    /// Get the langages in the snapshot.
+    #[must_use]
+    pub fn langs(&self) -> Vec<Lang> {
+        self.langs
+            .keys()
+            .filter_map(|code| code.parse().ok())
+            .collect()
+    }
+
+    /// Get the data of `lang` langage, if downloaded.
+    pub(crate) fn lang(&self, lang: Lang) -> Option<&LangSnapshot> {
+        self.langs.get(lang.code())
+    }
+}
+
+impl LangSnapshot {
+    fn download(tcgdex: &Tcgdex, lang: Lang) -> errors::Result<Self> {
+        let mut lists = BTreeMap::new();
+        for name in LISTS {
+            match fetch_value(tcgdex, lang, name, None) {
+                Ok(list) => {
+                    lists.insert(name.to_string(), list);
+                }
+                Err(error) if error.is_not_found() => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        let series = fetch_value(tcgdex, lang, "series", None)?;
+        let series = fetch_all(tcgdex, lang, "series", &ids(&series))?;
+        let sets = fetch_value(tcgdex, lang, "sets", None)?;
+        let sets = fetch_all(tcgdex, lang, "sets", &ids(&sets))?;
+        let card_ids: Vec<String> = sets
+            .iter()
+            .flat_map(|set| ids(set.get("cards").unwrap_or(&Value::Null)))
+            .collect();
+        let cards = fetch_all(tcgdex, lang, "cards", &card_ids)?;
+
+        Ok(Self {
+            series,
+            sets,
+            cards,
+            lists,
+        })
+    }
+}
+
+impl LangSnapshot {
+    /// Update data with remote data modified since `since` (seconds since UNIX epoch).
+    fn sync(&mut self, tcgdex: &Tcgdex, lang: Lang, since: u64) -> errors::Result<LangDiff> {
+        let mut diff = LangDiff::default();
+
+        // series: only added and removed ones.
+        let remote_series = ids(&fetch_value(tcgdex, lang, "series", None)?);
+        let local_series = objects_ids(&self.series);
+        diff.series.removed = missing(&local_series, &remote_series);
+        let removed = id_set(&diff.series.removed);
+        self.series
+            .retain(|serie| !removed.contains(id(serie).as_str()));
+        for serie in fetch_all(
+            tcgdex,
+            lang,
+            "series",
+            &missing(&remote_series, &local_series),
+        )? {
+            diff.series.added.push(id(&serie));
+            self.series.push(serie);
+        }
+
+        // sets: new ones, and existing ones that may have new or removed cards.
+        let remote_sets = fetch_value(tcgdex, lang, "sets", None)?;
+        let local_sets = objects_ids(&self.sets);
+        diff.sets.removed = missing(&local_sets, &ids(&remote_sets));
+        let removed = id_set(&diff.sets.removed);
+        self.sets.retain(|set| !removed.contains(id(set).as_str()));
+        self.cards.retain(|card| {
+            let removed = removed.contains(text_at(card, "set.id").as_str());
+            if removed {
+                diff.cards.removed.push(id(card));
+            }
+            !removed
+        });
+        let since_date = date_time(since)[..10].to_string();
+        let local_sets: HashMap<String, &Value> =
+            self.sets.iter().map(|set| (id(set), set)).collect();
+        let to_fetch: Vec<String> = remote_sets
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|remote| match local_sets.get(&id(remote)) {
+                Some(set) => {
+                    set.pointer("/cardCount/total") != remote.pointer("/cardCount/total")
+                        || text_at(set, "releaseDate") >= since_date
+                }
+                None => true,
+            })
+            .map(id)
+            .collect();
+        let set_indexes = indexes(&self.sets);
+        let mut new_cards = Vec::new();
+        let mut removed_cards = Vec::new();
+        for set in fetch_all(tcgdex, lang, "sets", &to_fetch)? {
+            let set_id = id(&set);
+            let cards = ids(set.get("cards").unwrap_or(&Value::Null));
+            match set_indexes.get(&set_id).copied() {
+                Some(index) => {
+                    let old_cards = ids(self.sets[index].get("cards").unwrap_or(&Value::Null));
+                    removed_cards.extend(missing(&old_cards, &cards));
+                    new_cards.extend(missing(&cards, &old_cards));
+                    if self.sets[index] != set {
+                        diff.sets.changed.push(set_id);
+                        self.sets[index] = set;
+                    }
+                }
+                None => {
+                    new_cards.extend(cards);
+                    diff.sets.added.push(set_id);
+                    self.sets.push(set);
+                }
+            }
+        }
+        let removed = id_set(&removed_cards);
+        self.cards
+            .retain(|card| !removed.contains(id(card).as_str()));
+        diff.cards.removed.extend(removed_cards);
+        for card in fetch_all(tcgdex, lang, "cards", &new_cards)? {
+            diff.cards.added.push(id(&card));
+            self.cards.push(card);
+        }
+
+        // cards updated since last sync.
+        let updated = Query::new().with_filter(Filter::field(Field::UPDATED).gt(date_time(since)));
+        let updated = match fetch_value(tcgdex, lang, "cards", Some(&updated)) {
+            Ok(cards) => ids(&cards),
+            Err(error) if error.is_not_found() => Vec::new(),
+            Err(error) => return Err(error),
+        };
+        let card_indexes = indexes(&self.cards);
+        let added = id_set(&diff.cards.added);
+        let updated: Vec<String> = updated
+            .into_iter()
+            .filter(|card| card_indexes.contains_key(card) && !added.contains(card.as_str()))
+            .collect();
+        for card in fetch_all(tcgdex, lang, "cards", &updated)? {
+            let card_id = id(&card);
+            if let Some(&index) = card_indexes.get(&card_id) {
+                if self.cards[index] != card {
+                    self.cards[index] = card;
+                    diff.cards.changed.push(card_id);
+                }
+            }
+        }
+
+        Ok(diff)
+    }
+}
+
+/// Get the id of a JSON object.
+fn id(object: &Value) -> String {
+    text_at(object, "id")
+}
+
+/// Get the text at `path` (like `set.id`) in a JSON object, or an empty string.
+fn text_at(object: &Value, path: &str) -> String {
+    path.split('.')
+        .try_fold(object, |value, key| value.get(key))
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Get the ids of JSON objects.
+fn objects_ids(objects: &[Value]) -> Vec<String> {
+    objects.iter().map(id).collect()
+}
+
+/// Get the index of JSON objects by id.
+fn indexes(objects: &[Value]) -> HashMap<String, usize> {
+    objects
+        .iter()
+        .enumerate()
+        .map(|(index, object)| (id(object), index))
+        .collect()
+}
+
+/// Get a set of `ids`, to look them up quickly.
+fn id_set(ids: &[String]) -> HashSet<&str> {
+    ids.iter().map(String::as_str).collect()
+}
+
+/// Get the ids of `ids` not in `others`.
+fn missing(ids: &[String], others: &[String]) -> Vec<String> {
+    let others = id_set(others);
+    ids.iter()
+        .filter(|id| !others.contains(id.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// Format seconds since UNIX epoch as an UTC date and time, like `2024-06-18T09:30:00Z`.
+pub(crate) fn date_time(secs: u64) -> String {
+    // days to civil date, from Howard Hinnant's algorithm.
+    let z = secs / 86_400 + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    let time = secs % 86_400;
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+/// Get the current time in seconds since UNIX epoch.
+pub(crate) fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Get the ids of the objects of a JSON list.
+pub(crate) fn ids(list: &Value) -> Vec<String> {
+    list.as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.get("id")?.as_str().map(ToString::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Get `object_name` endpoint response to `query`, as returned by the API.
+pub(crate) fn fetch_value(
+    tcgdex: &Tcgdex,
+    lang: Lang,
+    object_name: &str,
+    query: Option<&Query>,
+) -> errors::Result<Value> {
+    let url = endpoint_url(&tcgdex.base_url, lang, object_name, query);
+    let value: Value = tcgdex.get(&url)?;
+    if value.as_object().is_some_and(serde_json::Map::is_empty) {
+        return Err(ApiError::EmptyResponse);
+    }
+    Ok(value)
+}
+
+/// Get objects `ids` of `object_name` endpoint, a few at once, skipping missing ones.
+pub(crate) fn fetch_all(
+    tcgdex: &Tcgdex,
+    lang: Lang,
+    object_name: &str,
+    ids: &[String],
+) -> errors::Result<Vec<Value>> {
+    let results = crate::map_concurrently(ids, |id| {
+        fetch_value(tcgdex, lang, object_name, Some(&Query::new().with_id(id)))
+    });
+    let mut values = Vec::with_capacity(ids.len());
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            // listed but not available in this langage.
+            Err(error) if error.is_not_found() => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(values)
+}
@@ -0,0 +1,128 @@
+/// Define an enum matching a TCGdex string value, with an `Unknown(String)` fallback
+/// for values not known by this library.
+///
+/// Each variant is given with the (english) string returned by TCGdex API, followed by
+/// its name in other langages. A langage without name uses the english one.
+///
+/// Variants are langage independent: a value is decoded from its name in any langage,
+/// and encoded with its english name.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $value:literal $(, $lang:ident: $localized:literal)*;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+        #[serde(from = "String", into = "String")]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+
+            /// A value not known by this library, or any value in a langage whose names are not known
+            /// (see [`Lang::has_value_names`](crate::Lang::has_value_names)).
+            Unknown(String),
+        }
+
+        impl $name {
+            /// Get the langage independent value, as returned by TCGdex API in english.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Unknown(value) => value,
+                }
+            }
+
+            /// Get the value name in `lang` langage.
+            ///
+            /// Regional langages use their parent langage names (`PT` names for `PtBR` for example).
+            /// Langages without known names (see [`Lang::has_value_names`](crate::Lang::has_value_names))
+            /// get the english name: use `try_localized` to detect them.
+            #[must_use]
+            #[allow(unreachable_patterns)]
+            pub fn localized(&self, lang: crate::Lang) -> &str {
+                match self {
+                    $(Self::$variant => match lang.parent() {
+                        $(crate::Lang::$lang => $localized,)*
+                        _ => $value,
+                    },)*
+                    Self::Unknown(value) => value,
+                }
+            }
+
+            /// Get the value name in `lang` langage, or None if names in this langage are not known
+            /// (see [`Lang::has_value_names`](crate::Lang::has_value_names)).
+            ///
+            /// An `Unknown` value always gives its own name.
+            #[must_use]
+            pub fn try_localized(&self, lang: crate::Lang) -> Option<&str> {
+                match self {
+                    Self::Unknown(value) => Some(value),
+                    known if lang.has_value_names() => Some(known.localized(lang)),
+                    _ => None,
+                }
+            }
+        }
+
+        impl crate::string_enum::RequestName for $name {
+            fn request_name(&self, lang: crate::Lang) -> crate::errors::Result<&str> {
+                self.try_localized(lang)
+                    .ok_or_else(|| crate::errors::ApiError::UnknownValueName {
+                        value: self.as_str().to_string(),
+                        lang,
+                    })
+            }
+        }
+
+        impl From<String> for $name {
+            #[allow(unreachable_patterns)]
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($value $(| $localized)* => Self::$variant,)*
+                    _ => Self::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::Unknown(value) => value,
+                    known => known.as_str().to_string(),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+
+        // An empty unknown value, like an empty string before typing.
+        impl Default for $name {
+            fn default() -> Self {
+                Self::Unknown(String::new())
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;
+
+/// A value requested by its name, like `Fire` in `types/Fire`.
+pub(crate) trait RequestName {
+    /// Get the value name in `lang` langage, to be requested.
+    ///
+    /// # Errors
+    ///
+    /// If names in `lang` langage are not known.
+    fn request_name(&self, lang: crate::Lang) -> crate::errors::Result<&str>;
+}
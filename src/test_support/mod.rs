@@ -0,0 +1,413 @@
+//! Local mock of TCGdex API to test without network, available with `test-support` feature.
+//!
+//! [`MockServer`] is an in-process HTTP server serving recorded JSON fixtures.
+//! Requests to an unknown target get a TCGdex "not found" error body, like the real API.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use tcgdex_api::test_support::MockServer;
+//! # use tcgdex_api::{Card, Query};
+//! let server = MockServer::start();
+//! let tcgdex = server.tcgdex();
+//! let card: Card = tcgdex
+//!     .cards()
+//!     .fetch(Some(&Query::new().with_id("swsh3-136")))
+//!     .unwrap();
+//! assert_eq!(card.name, "Furret");
+//! ```
+
+use crate::Tcgdex;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::JoinHandle;
+
+/// Recorded fixtures, keyed by request target (path and query string).
+const FIXTURES: &[(&str, &str)] = &[
+    ("/en/types", include_str!("fixtures/en/types.json")),
+    (
+        "/en/categories",
+        include_str!("fixtures/en/categories.json"),
+    ),
+    ("/en/hp", include_str!("fixtures/en/hp.json")),
+    (
+        "/en/illustrators",
+        include_str!("fixtures/en/illustrators.json"),
+    ),
+    ("/en/rarities", include_str!("fixtures/en/rarities.json")),
+    ("/en/retreats", include_str!("fixtures/en/retreats.json")),
+    (
+        "/en/types/Fire",
+        include_str!("fixtures/en/types/fire.json"),
+    ),
+    (
+        "/en/illustrators/Ken%20Sugimori",
+        include_str!("fixtures/en/illustrators/ken_sugimori.json"),
+    ),
+    ("/en/hp/60", include_str!("fixtures/en/hp/60.json")),
+    ("/en/stages", include_str!("fixtures/en/stages.json")),
+    ("/en/suffixes", include_str!("fixtures/en/suffixes.json")),
+    (
+        "/en/trainer-types",
+        include_str!("fixtures/en/trainer-types.json"),
+    ),
+    (
+        "/en/energy-types",
+        include_str!("fixtures/en/energy-types.json"),
+    ),
+    (
+        "/en/regulation-marks",
+        include_str!("fixtures/en/regulation-marks.json"),
+    ),
+    ("/en/variants", include_str!("fixtures/en/variants.json")),
+    ("/en/dex-ids", include_str!("fixtures/en/dex-ids.json")),
+    ("/en/series", include_str!("fixtures/en/series.json")),
+    (
+        "/en/series/swsh",
+        include_str!("fixtures/en/series/swsh.json"),
+    ),
+    (
+        "/en/series?name=Sword%20%26%20Shield",
+        include_str!("fixtures/en/queries/series_name_sword.json"),
+    ),
+    ("/en/sets", include_str!("fixtures/en/sets.json")),
+    (
+        "/en/sets/swsh3",
+        include_str!("fixtures/en/sets/swsh3.json"),
+    ),
+    (
+        "/en/sets/swsh3/136",
+        include_str!("fixtures/en/cards/swsh3-136.json"),
+    ),
+    (
+        "/en/sets?cardCount.total=201",
+        include_str!("fixtures/en/queries/sets_card_count_total_201.json"),
+    ),
+    ("/en/cards", include_str!("fixtures/en/cards.json")),
+    (
+        "/en/cards/swsh3-136",
+        include_str!("fixtures/en/cards/swsh3-136.json"),
+    ),
+    (
+        "/en/cards/A1-132",
+        include_str!("fixtures/en/cards/A1-132.json"),
+    ),
+    (
+        "/en/cards?name=furret&id=ex",
+        include_str!("fixtures/en/queries/cards_name_furret_id_ex.json"),
+    ),
+    ("/fr/types", include_str!("fixtures/fr/types.json")),
+    (
+        "/fr/cards/swsh3-136",
+        include_str!("fixtures/fr/cards/swsh3-136.json"),
+    ),
+];
+
+/// A response served by [`MockServer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse {
+    /// The HTTP status code.
+    pub status: u16,
+
+    /// Headers added to the response (`Content-Type` and `Content-Length` are always sent).
+    pub headers: Vec<(String, String)>,
+
+    /// The response body.
+    pub body: String,
+}
+
+impl MockResponse {
+    /// Create a successful response with `body` as JSON content.
+    #[must_use]
+    pub fn json(body: &str) -> Self {
+        Self {
+            status: 200,
+            headers: Vec::new(),
+            body: body.to_string(),
+        }
+    }
+
+    /// Create a TCGdex error response, like the API does for an unknown resource.
+    #[must_use]
+    pub fn tcgdex_error(status: u16, title: &str, endpoint: &str) -> Self {
+        let kind = if status == 404 {
+            "not-found"
+        } else {
+            "bad-request"
+        };
+        let body = serde_json::json!({
+            "type": format!("https://tcgdex.dev/errors/{kind}"),
+            "title": title,
+            "status": status,
+            "endpoint": endpoint,
+            "method": "GET",
+        });
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.to_string(),
+        }
+    }
+
+    /// Set the HTTP status code.
+    #[must_use]
+    pub const fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Add a header to the response.
+    #[must_use]
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A request received by [`MockServer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// The HTTP method.
+    pub method: String,
+
+    /// The request target: path and query string.
+    pub target: String,
+
+    /// The request headers, names in lower case.
+    pub headers: Vec<(String, String)>,
+}
+
+impl RecordedRequest {
+    /// Get the value of header `name` (case insensitive).
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// State shared between [`MockServer`] and its thread.
+#[derive(Debug, Default)]
+struct State {
+    // responses are served in order, the last one is repeated.
+    routes: Mutex<HashMap<String, Vec<MockResponse>>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+    stopped: AtomicBool,
+}
+
+/// In-process HTTP server mocking TCGdex API.
+///
+/// The server listens on a random local port and stops when dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    address: SocketAddr,
+    state: Arc<State>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Start a server serving recorded fixtures.
+    ///
+    /// # Panics
+    ///
+    /// If no local port can be bound.
+    #[must_use]
+    pub fn start() -> Self {
+        let server = Self::empty();
+        for (target, body) in FIXTURES {
+            server.mount(target, MockResponse::json(body));
+        }
+        server
+    }
+
+    /// Start a server without any fixture. Use [`MockServer::mount`] to add responses.
+    ///
+    /// # Panics
+    ///
+    /// If no local port can be bound.
+    #[must_use]
+    pub fn empty() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("cannot bind a local port");
+        let address = listener.local_addr().expect("cannot get local address");
+        let state = Arc::new(State::default());
+
+        let thread_state = Arc::clone(&state);
+        let thread = std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if thread_state.stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let connection_state = Arc::clone(&thread_state);
+                    std::thread::spawn(move || {
+                        // A broken connection only concerns the client that opened it.
+                        let _ = handle_connection(stream, &connection_state);
+                    });
+                }
+            }
+        });
+
+        Self {
+            address,
+            state,
+            thread: Some(thread),
+        }
+    }
+
+    /// Serve `response` for requests to `target` (path and query string, for example `/en/cards?name=furret`).
+    ///
+    /// Replace any response already mounted for this target.
+    pub fn mount(&self, target: &str, response: MockResponse) {
+        self.state
+            .routes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(target.to_string(), vec![response]);
+    }
+
+    /// Serve `responses` in order for requests to `target`, then repeat the last one.
+    ///
+    /// Replace any response already mounted for this target.
+    ///
+    /// # Panics
+    ///
+    /// If `responses` is empty.
+    pub fn mount_sequence(&self, target: &str, responses: Vec<MockResponse>) {
+        assert!(!responses.is_empty(), "a sequence needs a response");
+        self.state
+            .routes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(target.to_string(), responses);
+    }
+
+    /// Get the base URL to give to [`TcgdexBuilder::with_base_url`](crate::TcgdexBuilder::with_base_url).
+    #[must_use]
+    pub fn base_url(&self) -> String {
+        format!("http://{}/", self.address)
+    }
+
+    /// Get a [`Tcgdex`] sending its requests to this server.
+    ///
+    /// # Panics
+    ///
+    /// If the HTTP client cannot be built.
+    #[must_use]
+    pub fn tcgdex(&self) -> Tcgdex {
+        Tcgdex::builder()
+            .with_base_url(&self.base_url())
+            .build()
+            .expect("cannot build client")
+    }
+
+    /// Get an `AsyncTcgdex` sending its requests to this server.
+    ///
+    /// # Panics
+    ///
+    /// If the HTTP client cannot be built.
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub fn async_tcgdex(&self) -> crate::AsyncTcgdex {
+        Tcgdex::builder()
+            .with_base_url(&self.base_url())
+            .build_async()
+            .expect("cannot build client")
+    }
+
+    /// Get all requests received so far, in arrival order.
+    #[must_use]
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state
+            .requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.state.stopped.store(true, Ordering::SeqCst);
+        // wake up the thread blocked on accept.
+        let _ = TcpStream::connect(self.address);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Read one request from `stream` and write the matching response.
+fn handle_connection(mut stream: TcpStream, state: &State) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+    }
+
+    let response = state
+        .routes
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_mut(&target)
+        .and_then(|responses| {
+            if responses.len() > 1 {
+                Some(responses.remove(0))
+            } else {
+                responses.first().cloned()
+            }
+        })
+        .unwrap_or_else(|| {
+            let endpoint = target.split('?').next().unwrap_or_default();
+            MockResponse::tcgdex_error(
+                404,
+                "The resource you are trying to reach does not exists",
+                endpoint,
+            )
+        });
+
+    state
+        .requests
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(RecordedRequest {
+            method,
+            target,
+            headers,
+        });
+
+    let mut head = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.body.len()
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(response.body.as_bytes())?;
+    stream.flush()
+}
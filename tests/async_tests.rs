@@ -0,0 +1,190 @@
+use std::time::Duration;
+use tcgdex_api::cache::MemoryCache;
+use tcgdex_api::endpoints::cards::{Card, CardBrief};
+use tcgdex_api::endpoints::sets::Set;
+use tcgdex_api::query::Query;
+use tcgdex_api::retry::RetryPolicy;
+use tcgdex_api::test_support::{MockResponse, MockServer};
+use tcgdex_api::{Lang, TcgdexBuilder};
+
+#[tokio::test]
+async fn get_types() {
+    let server = MockServer::start();
+    let tcgdex = server.async_tcgdex();
+    let types = tcgdex
+        .types()
+        .fetch()
+        .await
+        .expect("The API should returns a types list");
+    assert_eq!(types.len(), 11)
+}
+
+#[tokio::test]
+async fn get_stages() {
+    let server = MockServer::start();
+    let tcgdex = server.async_tcgdex();
+    let stages = tcgdex
+        .stages()
+        .fetch()
+        .await
+        .expect("The API should returns a stages list");
+    assert_eq!(stages.len(), 10)
+}
+
+#[tokio::test]
+async fn get_specific_set() {
+    let server = MockServer::start();
+    let tcgdex = server.async_tcgdex();
+    let filter = Query::new().with_id("swsh3");
+    let set: Set = tcgdex
+        .sets()
+        .fetch(Some(&filter))
+        .await
+        .expect("The API should returns a set");
+    assert_eq!(set.id, "swsh3");
+    assert_eq!(set.name, "Darkness Ablaze")
+}
+
+#[tokio::test]
+async fn get_specific_card() {
+    let server = MockServer::start();
+    let tcgdex = server.async_tcgdex();
+    let filter = Query::new().with_id("swsh3-136");
+    let card: Card = tcgdex
+        .cards()
+        .fetch(Some(&filter))
+        .await
+        .expect("The API should returns a card");
+    assert_eq!(card.id, "swsh3-136");
+    assert_eq!(card.name, "Furret");
+}
+
+#[tokio::test]
+async fn get_filtered_cards() {
+    let server = MockServer::start();
+    let tcgdex = server.async_tcgdex();
+    let filter = Query::new().with_filtering(vec!["name=furret", "id=ex"]);
+    let cards: Vec<CardBrief> = tcgdex
+        .cards()
+        .fetch(Some(&filter))
+        .await
+        .expect("The API should returns a cards list");
+    assert_eq!(cards[0].id, "ex7-22");
+}
+
+#[tokio::test]
+async fn get_tcgdex_error_message() {
+    let server = MockServer::start();
+    let tcgdex = server.async_tcgdex();
+    let filter = Query::new().with_id("sih3-136");
+
+    let error = tcgdex
+        .cards()
+        .fetch::<Card>(Some(&filter))
+        .await
+        .expect_err("The API should returns an error");
+    assert!(error.is_tcgdexapi());
+}
+
+#[tokio::test]
+async fn get_card_in_several_langs() {
+    let server = MockServer::start();
+    let tcgdex = server.async_tcgdex();
+    let cards = tcgdex
+        .cards()
+        .fetch_langs("swsh3-136", &[Lang::EN, Lang::FR, Lang::DE])
+        .await
+        .expect("The API should returns cards");
+    assert_eq!(cards.len(), 2);
+    assert_eq!(cards[&Lang::FR].name, "Fouinar");
+}
+
+#[tokio::test]
+async fn paginate_cards() {
+    use futures_util::StreamExt;
+
+    let server = MockServer::empty();
+    server.mount(
+        "/en/cards?pagination:page=1&pagination:itemsPerPage=1",
+        MockResponse::json(r#"[{"id":"ex7-22","localId":"22","name":"Furret"}]"#),
+    );
+    server.mount(
+        "/en/cards?pagination:page=2&pagination:itemsPerPage=1",
+        MockResponse::json(r#"[{"id":"ex12-33","localId":"33","name":"Furret"}]"#),
+    );
+    server.mount(
+        "/en/cards?pagination:page=3&pagination:itemsPerPage=1",
+        MockResponse::json("[]"),
+    );
+    let tcgdex = server.async_tcgdex();
+    let cards: Vec<CardBrief> = tcgdex
+        .cards()
+        .paginate(None, 1)
+        .map(|card| card.expect("The API should returns a card"))
+        .collect()
+        .await;
+    assert_eq!(cards.len(), 2);
+    assert_eq!(cards[1].id, "ex12-33");
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn fresh_responses_come_from_cache() {
+    let server = MockServer::start();
+    let tcgdex = TcgdexBuilder::new()
+        .with_base_url(&server.base_url())
+        .with_cache(MemoryCache::new(10), Duration::from_secs(3600))
+        .build_async()
+        .expect("The client should be built");
+    for _ in 0..2 {
+        let card: Card = tcgdex
+            .cards()
+            .fetch(Some(&Query::new().with_id("swsh3-136")))
+            .await
+            .expect("The API should returns a card");
+        assert_eq!(card.name, "Furret");
+    }
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn failed_requests_are_retried() {
+    let server = MockServer::start();
+    server.mount_sequence(
+        "/en/cards/swsh3-136",
+        vec![
+            MockResponse::json("").with_status(502),
+            MockResponse::json(r#"{"id":"swsh3-136","localId":"136","name":"Furret"}"#),
+        ],
+    );
+    let tcgdex = TcgdexBuilder::new()
+        .with_base_url(&server.base_url())
+        .with_retry(
+            RetryPolicy::new().with_backoff(Duration::from_millis(1), Duration::from_millis(10)),
+        )
+        .build_async()
+        .expect("The client should be built");
+    let card: Card = tcgdex
+        .cards()
+        .fetch(Some(&Query::new().with_id("swsh3-136")))
+        .await
+        .expect("The second attempt should succeed");
+    assert_eq!(card.name, "Furret");
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn fetch_many_cards() {
+    let server = MockServer::start();
+    let tcgdex = server.async_tcgdex();
+    let cards = tcgdex
+        .cards()
+        .fetch_many(&["A1-132", "swsh3-136", "A1-132", "test-1"])
+        .await;
+    let fetched: Vec<&str> = cards.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(fetched, vec!["A1-132", "swsh3-136", "test-1"]);
+    assert!(cards[0].1.is_ok());
+    assert!(cards[1].1.is_ok());
+    assert!(cards[2].1.is_err());
+    assert_eq!(server.requests().len(), 3);
+}
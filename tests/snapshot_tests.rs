@@ -0,0 +1,298 @@
+use std::path::PathBuf;
+use tcgdex_api::endpoints::cards::{Card, CardBrief};
+use tcgdex_api::endpoints::series::SerieBrief;
+use tcgdex_api::endpoints::sets::{Set, SetBrief};
+use tcgdex_api::errors::SnapshotError;
+use tcgdex_api::filter::{Field, Filter};
+use tcgdex_api::query::{Order, Query};
+use tcgdex_api::snapshot::{Snapshot, SNAPSHOT_VERSION};
+use tcgdex_api::test_support::{MockResponse, MockServer};
+use tcgdex_api::{Lang, PokemonType, Tcgdex, TcgdexBuilder};
+
+/// Get a file path in the temporary directory, unique for this test process.
+fn temp_file(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("tcgdex-{}-{name}", std::process::id()))
+}
+
+/// Get an offline client on a snapshot written by hand.
+fn offline_tcgdex(name: &str) -> Tcgdex {
+    let path = temp_file(name);
+    let content = r#"{
+        "version": 1,
+        "created_at": 1700000000,
+        "langs": {
+            "en": {
+                "series": [{"id": "swsh", "name": "Sword & Shield", "logo": "https://assets.tcgdex.net/en/swsh/swsh3/logo", "sets": []}],
+                "sets": [
+                    {"id": "swsh1", "name": "Sword & Shield", "releaseDate": "2020-02-07", "cardCount": {"total": 216, "official": 202, "holo": 5}, "cards": []},
+                    {"id": "swsh3", "name": "Darkness Ablaze", "releaseDate": "2020-08-14", "cardCount": {"total": 201, "official": 189, "holo": 7}, "cards": []}
+                ],
+                "cards": [
+                    {"id": "swsh3-136", "localId": "136", "name": "Furret", "hp": 110, "types": ["Colorless"], "set": {"id": "swsh3"}},
+                    {"id": "swsh3-20", "localId": "20", "name": "Victini", "hp": 80, "types": ["Fire"], "set": {"id": "swsh3"}},
+                    {"id": "swsh1-25", "localId": "25", "name": "Pikachu", "hp": 60, "types": ["Lightning"], "evolveFrom": null, "set": {"id": "swsh1"}},
+                    {"id": "swsh1-26", "localId": "26", "name": "Raichu", "hp": 110, "types": ["Lightning"], "evolveFrom": "Pikachu", "set": {"id": "swsh1"}}
+                ],
+                "lists": {"types": ["Colorless", "Fire", "Lightning"]}
+            }
+        }
+    }"#;
+    std::fs::write(&path, content).expect("The snapshot should be written");
+    let snapshot = Snapshot::load(&path).expect("The snapshot should be loaded");
+    let _ = std::fs::remove_file(&path);
+    TcgdexBuilder::new()
+        .with_snapshot(snapshot)
+        .build()
+        .expect("The client should be built")
+}
+
+fn card_ids(tcgdex: &Tcgdex, query: &Query) -> Vec<String> {
+    tcgdex
+        .cards()
+        .fetch::<Vec<CardBrief>>(Some(query))
+        .map(|cards| cards.into_iter().map(|card| card.id).collect())
+        .unwrap_or_default()
+}
+
+#[test]
+fn download_save_and_load() {
+    let server = MockServer::start();
+    let snapshot = Snapshot::download(&server.tcgdex(), &[Lang::EN])
+        .expect("The snapshot should be downloaded");
+    assert_eq!(snapshot.version(), SNAPSHOT_VERSION);
+    assert_eq!(snapshot.langs(), vec![Lang::EN]);
+
+    let path = temp_file("download.json");
+    snapshot.save(&path).expect("The snapshot should be saved");
+    let loaded = Snapshot::load(&path).expect("The snapshot should be loaded");
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(loaded, snapshot);
+
+    let requests = server.requests().len();
+    let tcgdex = TcgdexBuilder::new()
+        .with_snapshot(loaded)
+        .build()
+        .expect("The client should be built");
+    let card: Card = tcgdex
+        .cards()
+        .fetch(Some(&Query::new().with_id("swsh3-136")))
+        .expect("The snapshot should have the card");
+    assert_eq!(card.name, "Furret");
+    let set: Set = tcgdex
+        .sets()
+        .fetch(Some(&Query::new().with_id("swsh3")))
+        .expect("The snapshot should have the set");
+    assert_eq!(set.name, "Darkness Ablaze");
+    let sets: Vec<SetBrief> = tcgdex
+        .sets()
+        .fetch(None)
+        .expect("The snapshot should have sets");
+    assert_eq!(sets.len(), 1);
+    assert_eq!(sets[0].card_count.total, 201);
+    let series: Vec<SerieBrief> = tcgdex
+        .series()
+        .fetch(None)
+        .expect("The snapshot should have series");
+    assert_eq!(series[0].id, "swsh");
+    assert_eq!(tcgdex.types().fetch().expect("types").len(), 11);
+    assert_eq!(tcgdex.rarities().fetch().expect("rarities").len(), 28);
+    let card = tcgdex
+        .sets()
+        .fetch_card("darkness ablaze", "0136/189")
+        .expect("The snapshot should have the card");
+    assert_eq!(card.id, "swsh3-136");
+    // no request to the server.
+    assert_eq!(server.requests().len(), requests);
+}
+
+#[test]
+fn offline_filters() {
+    let tcgdex = offline_tcgdex("filters.json");
+    let by_name = Query::new().with_filtering(vec!["name=PIKA"]);
+    assert_eq!(card_ids(&tcgdex, &by_name), vec!["swsh1-25"]);
+    let strict = Query::new().with_filter(Filter::field(Field::NAME).eq("Pika"));
+    assert!(card_ids(&tcgdex, &strict).is_empty());
+    let or = Query::new().with_filter(Filter::field(Field::NAME).eq("Furret").or("Victini"));
+    assert_eq!(card_ids(&tcgdex, &or), vec!["swsh3-136", "swsh3-20"]);
+    let hp = Query::new()
+        .with_filter(Filter::field(Field::HP).gte(80))
+        .with_filter(Filter::field(Field::SET_ID).neq("swsh1"));
+    assert_eq!(card_ids(&tcgdex, &hp), vec!["swsh3-136", "swsh3-20"]);
+    let types = Query::new().with_filter(Filter::field(Field::TYPES).eq("Lightning"));
+    assert_eq!(card_ids(&tcgdex, &types), vec!["swsh1-25", "swsh1-26"]);
+    let basic = Query::new()
+        .with_filter(Filter::field(Field::EVOLVE_FROM).null())
+        .with_filter(Filter::field(Field::NAME).not_like("furret"));
+    assert_eq!(card_ids(&tcgdex, &basic), vec!["swsh3-20", "swsh1-25"]);
+
+    let sets: Vec<SetBrief> = tcgdex
+        .sets()
+        .fetch(Some(&Query::new().with_filter(
+            Filter::field(Field::RELEASE_DATE).gt("2020-05-01"),
+        )))
+        .expect("The snapshot should have sets");
+    assert_eq!(sets.len(), 1);
+    assert_eq!(sets[0].id, "swsh3");
+}
+
+#[test]
+fn offline_sorting_and_pagination() {
+    let tcgdex = offline_tcgdex("sorting.json");
+    let sorted = Query::new()
+        .with_sorting("hp", &Order::DESC)
+        .with_sorting("name", &Order::ASC);
+    assert_eq!(
+        card_ids(&tcgdex, &sorted),
+        vec!["swsh3-136", "swsh1-26", "swsh3-20", "swsh1-25"]
+    );
+    assert_eq!(
+        card_ids(&tcgdex, &sorted.clone().with_pagination(2, 3)),
+        vec!["swsh1-25"]
+    );
+    let ids: Vec<String> = tcgdex
+        .cards()
+        .paginate(Some(&sorted), 2)
+        .map(|card| card.expect("The snapshot should have cards").id)
+        .collect();
+    assert_eq!(ids.len(), 4);
+}
+
+#[test]
+fn offline_cards_for_list_values() {
+    let tcgdex = offline_tcgdex("cards_for.json");
+    let ids = |cards: Vec<CardBrief>| cards.into_iter().map(|card| card.id).collect::<Vec<_>>();
+    let cards = tcgdex
+        .types()
+        .cards_for(&PokemonType::Lightning)
+        .expect("The snapshot should have lightning cards");
+    assert_eq!(ids(cards), vec!["swsh1-25", "swsh1-26"]);
+    let cards = tcgdex
+        .hps()
+        .cards_for(110)
+        .expect("The snapshot should have cards with 110 HP");
+    assert_eq!(ids(cards), vec!["swsh3-136", "swsh1-26"]);
+    let error = tcgdex.hps().cards_for(999).expect_err("No card has 999 HP");
+    assert!(error.is_not_found());
+}
+
+#[test]
+fn offline_missing_objects() {
+    let mut tcgdex = offline_tcgdex("missing.json");
+    let error = tcgdex
+        .cards()
+        .fetch::<Card>(Some(&Query::new().with_id("swsh3-999")))
+        .expect_err("The card is not in the snapshot");
+    assert!(error.is_not_found());
+    let none = Query::new().with_filter(Filter::field(Field::HP).gt(500));
+    assert!(tcgdex
+        .cards()
+        .fetch::<Vec<CardBrief>>(Some(&none))
+        .expect_err("No card matches")
+        .is_empty_response());
+
+    tcgdex.set_lang(Lang::FR);
+    let error = tcgdex
+        .cards()
+        .fetch::<Card>(Some(&Query::new().with_id("swsh3-136")))
+        .expect_err("The langage is not in the snapshot");
+    assert!(error.is_not_found());
+}
+
+#[test]
+fn unsupported_snapshot_version() {
+    let path = temp_file("version.json");
+    std::fs::write(&path, r#"{"version": 99, "created_at": 0, "langs": {}}"#)
+        .expect("The snapshot should be written");
+    let error = Snapshot::load(&path).expect_err("The version is not supported");
+    let _ = std::fs::remove_file(&path);
+    assert!(matches!(error, SnapshotError::UnsupportedVersion(99)));
+}
+
+#[test]
+fn sync_reports_diff() {
+    let server = MockServer::start();
+    let path = temp_file("sync.json");
+    let content = r#"{
+        "version": 1,
+        "created_at": 1700000000,
+        "langs": {
+            "en": {
+                "series": [],
+                "sets": [
+                    {"id": "swsh3", "name": "Darkness Ablaze", "releaseDate": "2020-08-14", "cardCount": {"total": 200, "official": 189},
+                     "cards": [{"id": "swsh3-136", "localId": "136", "name": "Furret"}, {"id": "swsh3-999", "localId": "999", "name": "Test"}]},
+                    {"id": "old1", "name": "Old", "releaseDate": "1999-01-09", "cardCount": {"total": 1, "official": 1},
+                     "cards": [{"id": "old1-1", "localId": "1", "name": "Old card"}]}
+                ],
+                "cards": [
+                    {"id": "swsh3-136", "localId": "136", "name": "Old Furret", "set": {"id": "swsh3"}},
+                    {"id": "swsh3-999", "localId": "999", "name": "Test", "set": {"id": "swsh3"}},
+                    {"id": "old1-1", "localId": "1", "name": "Old card", "set": {"id": "old1"}}
+                ],
+                "lists": {}
+            }
+        }
+    }"#;
+    std::fs::write(&path, content).expect("The snapshot should be written");
+    let mut snapshot = Snapshot::load(&path).expect("The snapshot should be loaded");
+    let _ = std::fs::remove_file(&path);
+
+    server.mount(
+        "/en/cards/swsh3-1",
+        MockResponse::json(
+            r#"{"id":"swsh3-1","localId":"1","name":"Butterfree V","set":{"id":"swsh3"}}"#,
+        ),
+    );
+    // one day before the snapshot creation.
+    server.mount(
+        "/en/cards?updated=gt:2023-11-13T22%3A13%3A20Z",
+        MockResponse::json(r#"[{"id":"swsh3-136","localId":"136","name":"Furret"}]"#),
+    );
+
+    let diff = snapshot
+        .sync(&server.tcgdex())
+        .expect("The snapshot should be synced");
+    let en = &diff.langs[&Lang::EN];
+    assert_eq!(en.series.added, vec!["swsh"]);
+    assert!(en.series.removed.is_empty());
+    assert!(en.sets.added.is_empty());
+    assert_eq!(en.sets.changed, vec!["swsh3"]);
+    assert_eq!(en.sets.removed, vec!["old1"]);
+    assert_eq!(en.cards.added, vec!["swsh3-1"]);
+    assert_eq!(en.cards.changed, vec!["swsh3-136"]);
+    assert_eq!(en.cards.removed, vec!["old1-1", "swsh3-999"]);
+    assert!(snapshot.synced_at() > snapshot.created_at());
+
+    // only the updated sets and cards were requested.
+    let card_requests: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|request| request.target)
+        .filter(|target| target.starts_with("/en/cards/"))
+        .collect();
+    assert_eq!(
+        card_requests.len(),
+        5,
+        "4 new cards of swsh3 and 1 updated card"
+    );
+
+    let tcgdex = TcgdexBuilder::new()
+        .with_snapshot(snapshot.clone())
+        .build()
+        .expect("The client should be built");
+    let card: Card = tcgdex
+        .cards()
+        .fetch(Some(&Query::new().with_id("swsh3-136")))
+        .expect("The snapshot should have the card");
+    assert_eq!(card.name, "Furret");
+    assert!(tcgdex
+        .sets()
+        .fetch::<Set>(Some(&Query::new().with_id("old1")))
+        .expect_err("The set was removed")
+        .is_not_found());
+
+    let diff = snapshot
+        .sync(&server.tcgdex())
+        .expect("The snapshot should be synced");
+    assert!(diff.is_empty());
+}
@@ -1,82 +1,196 @@
+use std::time::{Duration, Instant, SystemTime};
+use tcgdex_api::assets::{Extension, Quality};
+use tcgdex_api::builder::DEFAULT_TIMEOUT;
+use tcgdex_api::cache::{Cache, CacheKey, CachedResponse, DiskCache, MemoryCache};
 use tcgdex_api::endpoints::cards::{Card, CardBrief};
 use tcgdex_api::endpoints::series::{Serie, SerieBrief};
 use tcgdex_api::endpoints::sets::{Set, SetBrief};
-use tcgdex_api::query::Query;
+use tcgdex_api::errors::QueryError;
+use tcgdex_api::lenient::Coercion;
+use tcgdex_api::mirror::ImageMirror;
+use tcgdex_api::query::{self, Query};
+use tcgdex_api::retry::{RateLimiter, RetryPolicy};
+use tcgdex_api::test_support::{MockResponse, MockServer};
 use tcgdex_api::*;
 
 #[test]
 fn get_types() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let types = tcgdex
         .types()
         .fetch()
         .expect("The API should returns a types list");
-    assert!(types.len() >= 11)
+    assert_eq!(types.len(), 11);
+    assert!(types.contains(&PokemonType::Psychic));
+    assert!(!types.iter().any(|t| matches!(t, PokemonType::Unknown(_))))
 }
 
 #[test]
 fn get_categories() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let categories = tcgdex
         .categories()
         .fetch()
         .expect("The API should returns a categories list");
-    assert!(categories.len() >= 3)
+    assert_eq!(
+        categories,
+        vec![Category::Energy, Category::Pokemon, Category::Trainer]
+    )
 }
 
 #[test]
 fn get_hps() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let hps = tcgdex
         .hps()
         .fetch()
         .expect("The API should returns a HP list");
-    assert!(hps.len() >= 32)
+    assert_eq!(hps.len(), 32)
 }
 
 #[test]
 fn get_illustrators() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let illustrators = tcgdex
         .illustrators()
         .fetch()
         .expect("The API should returns an illustrators list");
-    assert!(illustrators.len() >= 251)
+    assert_eq!(illustrators.len(), 20)
 }
 
 #[test]
 fn get_rarities() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let rarities = tcgdex
         .rarities()
         .fetch()
         .expect("The API should returns a rarities list");
-    assert!(rarities.len() >= 27)
+    assert_eq!(rarities.len(), 28);
+    assert!(rarities.contains(&Rarity::RareHoloLvX));
+    assert!(!rarities.iter().any(|r| matches!(r, Rarity::Unknown(_))))
 }
 
 #[test]
 fn get_retreats() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let retreats = tcgdex
         .retreats()
         .fetch()
         .expect("The API should returns a retreat costs list");
-    assert!(retreats.len() >= 5)
+    assert_eq!(retreats.len(), 6)
+}
+
+#[test]
+fn get_stages() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let stages = tcgdex
+        .stages()
+        .fetch()
+        .expect("The API should returns a stages list");
+    assert_eq!(stages.len(), 10);
+    assert!(stages.contains(&Stage::Stage1));
+}
+
+#[test]
+fn get_trainer_and_energy_types() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let trainer_types = tcgdex
+        .trainer_types()
+        .fetch()
+        .expect("The API should returns a trainer types list");
+    assert_eq!(trainer_types.len(), 8);
+    assert!(trainer_types.contains(&TrainerType::AceSpec));
+    let energy_types = tcgdex
+        .energy_types()
+        .fetch()
+        .expect("The API should returns an energy types list");
+    assert_eq!(energy_types, vec![EnergyType::Normal, EnergyType::Special]);
+}
+
+#[test]
+fn get_other_lists() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let suffixes = tcgdex
+        .suffixes()
+        .fetch()
+        .expect("The API should returns a suffixes list");
+    assert_eq!(suffixes.len(), 10);
+    let marks = tcgdex
+        .regulation_marks()
+        .fetch()
+        .expect("The API should returns a regulation marks list");
+    assert_eq!(marks, vec!["D", "E", "F", "G", "H"]);
+    let variants = tcgdex
+        .variants()
+        .fetch()
+        .expect("The API should returns a variants list");
+    assert!(variants.contains(&"reverse".to_string()));
+    let dex_ids = tcgdex
+        .dex_ids()
+        .fetch()
+        .expect("The API should returns a dex ids list");
+    assert_eq!(dex_ids.len(), 1025);
+    assert_eq!(dex_ids[24], 25);
+}
+
+#[test]
+fn get_cards_for_list_values() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let cards = tcgdex
+        .types()
+        .cards_for(&PokemonType::Fire)
+        .expect("The API should returns fire cards");
+    assert_eq!(cards.len(), 3);
+    assert_eq!(cards[0].name, "Charizard");
+    let cards = tcgdex
+        .illustrators()
+        .cards_for("Ken Sugimori")
+        .expect("The API should returns cards of an illustrator name with a space");
+    assert_eq!(cards.len(), 2);
+    let cards = tcgdex
+        .hps()
+        .cards_for(60)
+        .expect("The API should returns cards with 60 HP");
+    assert_eq!(cards[0].id, "base1-58");
+    assert_eq!(
+        server
+            .requests()
+            .last()
+            .map(|request| request.target.clone()),
+        Some("/en/hp/60".to_string())
+    );
+    let error = tcgdex
+        .rarities()
+        .cards_for(&Rarity::Unknown("Mythic".to_string()))
+        .expect_err("The rarity is unknown");
+    assert!(error.is_not_found());
 }
 
 #[test]
 fn get_all_series() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let series: Vec<SerieBrief> = tcgdex
         .series()
         .fetch(None)
         .expect("The API should returns a series list");
-    assert!(series.len() >= 19)
+    assert_eq!(series.len(), 13)
 }
 
 #[test]
 fn get_specific_serie() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let filter = Query::new().with_id("swsh");
     let serie: Serie = tcgdex
         .series()
@@ -88,7 +202,8 @@ fn get_specific_serie() {
 
 #[test]
 fn get_filtered_series() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let filter = Query::new().with_filtering(vec!["name=Sword & Shield"]);
     let series: Vec<SerieBrief> = tcgdex
         .series()
@@ -99,17 +214,19 @@ fn get_filtered_series() {
 
 #[test]
 fn get_all_sets() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let sets: Vec<SetBrief> = tcgdex
         .sets()
         .fetch(None)
         .expect("The API should returns a sets list");
-    assert!(sets.len() >= 173)
+    assert_eq!(sets.len(), 6)
 }
 
 #[test]
 fn get_specific_set() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let filter = Query::new().with_id("swsh3");
     let set: Set = tcgdex
         .sets()
@@ -125,7 +242,8 @@ fn get_specific_set() {
 
 #[test]
 fn get_filtered_sets() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let filter = Query::new().with_filtering(vec!["cardCount.total=201"]);
     let sets: Vec<SetBrief> = tcgdex
         .sets()
@@ -136,17 +254,19 @@ fn get_filtered_sets() {
 
 #[test]
 fn get_all_cards() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let cards: Vec<CardBrief> = tcgdex
         .cards()
         .fetch(None)
         .expect("The API should returns a cards list");
-    assert!(cards.len() >= 17915)
+    assert_eq!(cards.len(), 6)
 }
 
 #[test]
 fn get_specific_card() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let filter = Query::new().with_id("swsh3-136");
     let card: Card = tcgdex
         .cards()
@@ -157,26 +277,109 @@ fn get_specific_card() {
     assert_eq!(card.name, "Furret");
     assert_eq!(card.set.card_count.official, 189);
     assert_eq!(card.hp, 110);
-    assert_eq!(card.types, vec!["Colorless"]);
-    assert_eq!(card.weaknesses[0]._type, "Fighting");
+    assert_eq!(card.types, vec![PokemonType::Colorless]);
+    assert_eq!(card.category, Category::Pokemon);
+    assert_eq!(card.rarity, Rarity::Uncommon);
+    assert_eq!(card.stage, Some(Stage::Stage1));
+    assert_eq!(card.trainer_type, None);
+    assert_eq!(card.weaknesses[0]._type, PokemonType::Fighting);
+    assert_eq!(
+        card.attacks[1].cost,
+        vec![PokemonType::Colorless, PokemonType::Colorless]
+    );
+    assert_eq!(card.attacks[1].damage.value, 90);
+    assert_eq!(card.attacks[0].damage, Damage::default());
+    assert_eq!(card.retreat, 1);
+    assert!(card.legal.expanded);
+    assert!(!card.legal.standard);
+    assert_eq!(card.updated, "2024-06-18T00:34:39+02:00");
+    assert_eq!(card.variants_detailed.len(), 2);
+    assert_eq!(card.variants_detailed[1]._type, "reverse");
+}
+
+#[test]
+fn get_card_abilities_and_boosters() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let filter = Query::new().with_id("A1-132");
+    let card: Card = tcgdex
+        .cards()
+        .fetch(Some(&filter))
+        .expect("The API should returns a card");
+    assert_eq!(card.abilities.len(), 1);
+    assert_eq!(card.abilities[0]._type, "Ability");
+    assert_eq!(card.abilities[0].name, "Psy Shadow");
+    assert!(card.resistances.is_empty());
+    assert_eq!(card.retreat, 2);
+    assert_eq!(card.rarity, Rarity::ThreeDiamond);
+    assert_eq!(card.stage, Some(Stage::Stage2));
+    assert_eq!(card.boosters[0].id, "boo_A1-mewtwo");
+    assert_eq!(card.boosters[0].name, "Mewtwo");
+    assert!(card.boosters[0].artwork_front.ends_with("/front"));
+}
+
+#[test]
+fn get_attack_damage_modifiers() {
+    let server = MockServer::start();
+    server.mount(
+        "/en/cards/test-1",
+        MockResponse::json(
+            r#"{"id":"test-1","localId":"1","name":"Test","attacks":[
+                {"cost":["Fire","Water"],"name":"A","damage":"30+"},
+                {"cost":[],"name":"B","damage":"20×"},
+                {"cost":["Free"],"name":"C","damage":"50-"},
+                {"name":"D","damage":10}
+            ]}"#,
+        ),
+    );
+    let tcgdex = server.tcgdex();
+    let card: Card = tcgdex
+        .cards()
+        .fetch(Some(&Query::new().with_id("test-1")))
+        .expect("The API should returns a card");
+
+    let damages: Vec<(u16, DamageModifier)> = card
+        .attacks
+        .iter()
+        .map(|a| (a.damage.value, a.damage.modifier))
+        .collect();
+    assert_eq!(
+        damages,
+        vec![
+            (30, DamageModifier::Plus),
+            (20, DamageModifier::Times),
+            (50, DamageModifier::Minus),
+            (10, DamageModifier::None),
+        ]
+    );
+    assert_eq!(
+        card.attacks[0].cost,
+        vec![PokemonType::Fire, PokemonType::Water]
+    );
+    assert_eq!(
+        card.attacks[2].cost,
+        vec![PokemonType::Unknown("Free".to_string())]
+    );
 }
 
 #[test]
 fn get_filtered_cards() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let filter = Query::new().with_filtering(vec!["name=furret", "id=ex"]);
     let cards: Vec<CardBrief> = tcgdex
         .cards()
         .fetch(Some(&filter))
         .expect("The API should returns a cards list");
-    assert!(cards.len() >= 2);
+    assert_eq!(cards.len(), 2);
     assert_eq!(cards[0].id, "ex7-22");
     assert_eq!(cards[1].id, "ex12-33");
 }
 
 #[test]
 fn get_tcgdex_error_message() {
-    let tcgdex = Tcgdex::new();
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
     let filter = Query::new().with_id("sih3-136");
 
     let card_result = tcgdex.cards().fetch::<Card>(Some(&filter));
@@ -184,10 +387,10 @@ fn get_tcgdex_error_message() {
 
     let error = card_result.err().unwrap();
     assert!(error.is_tcgdexapi());
-    assert_eq!(
-        format!("{error}"),
-        "Tcgdex error : The resource you are trying to reach does not exists"
-    );
+    assert!(error.is_not_found());
+    let url = format!("{}en/cards/sih3-136", server.base_url());
+    assert_eq!(error.url(), Some(url.as_str()));
+    assert_eq!(format!("{error}"), format!("Not found : {url}"));
 
     let message = error.get_tcgdex_error().unwrap();
     assert_eq!(message._type, "https://tcgdex.dev/errors/not-found");
@@ -199,3 +402,1252 @@ fn get_tcgdex_error_message() {
     assert_eq!(message.endpoint, "/en/cards/sih3-136");
     assert_eq!(message.method, "GET");
 }
+
+#[test]
+fn builder_uses_base_url() {
+    let tcgdex = Tcgdex::builder()
+        .with_base_url("http://127.0.0.1:1")
+        .with_timeout(std::time::Duration::from_secs(5))
+        .build()
+        .expect("The client should be built");
+    let error = tcgdex
+        .sets()
+        .fetch::<Set>(Some(&Query::new().with_id("swsh3")))
+        .expect_err("Nothing should listen on this URL");
+    assert!(error.is_reqwest());
+}
+
+#[test]
+fn default_client_has_a_timeout() {
+    assert_eq!(TcgdexBuilder::new().timeout(), DEFAULT_TIMEOUT);
+    let builder = TcgdexBuilder::new().with_timeout(Duration::from_millis(200));
+    assert_eq!(builder.timeout(), Duration::from_millis(200));
+}
+
+#[test]
+fn get_card_in_another_lang() {
+    let server = MockServer::start();
+    let mut tcgdex = server.tcgdex();
+    tcgdex.set_lang(Lang::FR);
+    let filter = Query::new().with_id("swsh3-136");
+    let card: Card = tcgdex
+        .cards()
+        .fetch(Some(&filter))
+        .expect("The API should returns a card");
+    assert_eq!(card.name, "Fouinar");
+    assert_eq!(card.types, vec![PokemonType::Colorless]);
+    assert_eq!(card.category, Category::Pokemon);
+    assert_eq!(card.rarity, Rarity::Uncommon);
+    assert_eq!(card.stage, Some(Stage::Stage1));
+    assert_eq!(card.weaknesses[0]._type, PokemonType::Fighting);
+    assert_eq!(card.attacks[0].cost, vec![PokemonType::Colorless]);
+
+    assert_eq!(card.types[0].localized(Lang::FR), "Incolore");
+    assert_eq!(card.types[0].localized(Lang::EN), "Colorless");
+    assert_eq!(card.rarity.localized(Lang::FR), "Peu Commune");
+    assert_eq!(card.types[0].as_str(), "Colorless");
+
+    let types = tcgdex
+        .types()
+        .fetch()
+        .expect("The API should returns a types list");
+    assert!(!types.iter().any(|t| matches!(t, PokemonType::Unknown(_))));
+}
+
+#[test]
+fn parse_lang_tags() {
+    assert_eq!("en".parse(), Ok(Lang::EN));
+    assert_eq!("en-US".parse(), Ok(Lang::EN));
+    assert_eq!("pt".parse(), Ok(Lang::PT));
+    assert_eq!("pt-BR".parse(), Ok(Lang::PtBR));
+    assert_eq!("pt_PT".parse(), Ok(Lang::PtPT));
+    assert_eq!("es-419".parse(), Ok(Lang::ES));
+    assert_eq!("es-MX".parse(), Ok(Lang::EsMX));
+    assert_eq!("zh-Hans-CN".parse(), Ok(Lang::ZhCN));
+    assert_eq!("zh-Hant".parse(), Ok(Lang::ZhTW));
+    assert_eq!("ZH-TW".parse(), Ok(Lang::ZhTW));
+    assert_eq!("ja-JP".parse(), Ok(Lang::JA));
+    assert!("xx".parse::<Lang>().is_err());
+
+    for lang in Lang::ALL {
+        assert_eq!(lang.to_string().parse(), Ok(lang));
+    }
+    assert_eq!(Lang::ZhTW.code(), "zh-tw");
+    assert_eq!(Lang::PtBR.code(), "pt-br");
+}
+
+#[test]
+fn get_types_with_regional_lang() {
+    let server = MockServer::empty();
+    server.mount("/zh-tw/types", MockResponse::json(r#"["Colorless"]"#));
+    let mut tcgdex = server.tcgdex();
+    tcgdex.set_lang(Lang::ZhTW);
+    let types = tcgdex
+        .types()
+        .fetch()
+        .expect("The API should returns a types list");
+    assert_eq!(types, vec![PokemonType::Colorless]);
+    assert_eq!(PokemonType::Colorless.localized(Lang::PtBR), "Incolor");
+}
+
+#[test]
+fn values_in_langs_without_names() {
+    assert!(Lang::PtBR.has_value_names());
+    assert!(!Lang::JA.has_value_names());
+    assert_eq!(
+        Lang::ALL
+            .iter()
+            .filter(|lang| lang.has_value_names())
+            .count(),
+        9
+    );
+
+    // known names are not guessed: the API name is kept, and english is the documented fallback.
+    let fire: PokemonType = serde_json::from_str(r#""炎""#).unwrap();
+    assert_eq!(fire, PokemonType::Unknown("炎".to_string()));
+    assert_eq!(fire.try_localized(Lang::JA), Some("炎"));
+    assert_eq!(PokemonType::Fire.try_localized(Lang::JA), None);
+    assert_eq!(PokemonType::Fire.try_localized(Lang::PtBR), Some("Fogo"));
+    assert_eq!(PokemonType::Fire.localized(Lang::JA), "Fire");
+
+    // a value without name cannot be requested.
+    let server = MockServer::empty();
+    let mut tcgdex = server.tcgdex();
+    tcgdex.set_lang(Lang::JA);
+    let error = tcgdex
+        .types()
+        .cards_for(&PokemonType::Fire)
+        .expect_err("The japanese name of Fire is not known");
+    let unknown_name = |lang| errors::ApiError::UnknownValueName {
+        value: "Fire".to_string(),
+        lang,
+    };
+    assert_eq!(error, unknown_name(Lang::JA));
+    assert_ne!(error, unknown_name(Lang::KO));
+    assert!(server.requests().is_empty());
+    let _ = tcgdex
+        .types()
+        .cards_for(&fire)
+        .expect_err("The mock server has no cards");
+    assert_eq!(server.requests()[0].target, "/ja/types/%E7%82%8E");
+}
+
+#[test]
+fn get_card_in_several_langs() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let cards = tcgdex
+        .cards()
+        .fetch_langs("swsh3-136", &[Lang::EN, Lang::FR, Lang::DE])
+        .expect("The API should returns cards");
+    assert_eq!(cards.len(), 2);
+    assert_eq!(cards[&Lang::EN].name, "Furret");
+    assert_eq!(cards[&Lang::FR].name, "Fouinar");
+    assert!(!cards.contains_key(&Lang::DE));
+
+    let sets = tcgdex
+        .sets()
+        .fetch_langs("swsh3", &[Lang::EN, Lang::JA])
+        .expect("The API should returns sets");
+    assert_eq!(sets.len(), 1);
+    assert_eq!(sets[&Lang::EN].name, "Darkness Ablaze");
+}
+
+#[test]
+fn paginate_cards() {
+    let server = MockServer::empty();
+    server.mount(
+        "/en/cards?name=furret&pagination:page=1&pagination:itemsPerPage=2",
+        MockResponse::json(
+            r#"[{"id":"ex7-22","localId":"22","name":"Furret"},{"id":"ex12-33","localId":"33","name":"Furret"}]"#,
+        ),
+    );
+    server.mount(
+        "/en/cards?name=furret&pagination:page=2&pagination:itemsPerPage=2",
+        MockResponse::json(r#"[{"id":"swsh3-136","localId":"136","name":"Furret"}]"#),
+    );
+    let tcgdex = server.tcgdex();
+    let filter = Query::new().with_filtering(vec!["name=furret"]);
+    let ids: Vec<String> = tcgdex
+        .cards()
+        .paginate(Some(&filter), 2)
+        .map(|card| card.expect("The API should returns a card").id)
+        .collect();
+    assert_eq!(ids, vec!["ex7-22", "ex12-33", "swsh3-136"]);
+    // the last page is incomplete: no third request.
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn paginate_until_empty_page() {
+    let server = MockServer::empty();
+    server.mount(
+        "/en/series?pagination:page=1&pagination:itemsPerPage=1",
+        MockResponse::json(r#"[{"id":"swsh","name":"Sword & Shield"}]"#),
+    );
+    server.mount(
+        "/en/series?pagination:page=2&pagination:itemsPerPage=1",
+        MockResponse::json("[]"),
+    );
+    let tcgdex = server.tcgdex();
+    let series: Vec<SerieBrief> = tcgdex
+        .series()
+        .paginate(None, 1)
+        .collect::<Result<_, _>>()
+        .expect("The API should returns series");
+    assert_eq!(series.len(), 1);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn paginate_stops_on_error() {
+    let server = MockServer::empty();
+    let tcgdex = server.tcgdex();
+    let mut sets = tcgdex.sets().paginate(None, 10);
+    let error = sets
+        .next()
+        .expect("An error should be returned")
+        .expect_err("Nothing is mounted");
+    assert!(error.is_tcgdexapi());
+    assert!(sets.next().is_none());
+}
+
+#[test]
+fn build_typed_filters() {
+    let query = Query::new()
+        .with_filter(Filter::field(Field::NAME).like("pika"))
+        .with_filter(Filter::field(Field::NAME).not_like("raichu"))
+        .with_filter(Filter::field(Field::HP).gte(60).or(120))
+        .with_filter(Filter::field(Field::RETREAT).lt(2))
+        .with_filter(Filter::field(Field::SET_NAME).eq("Sword & Shield"))
+        .with_filter(Filter::field(Field::EVOLVE_FROM).null())
+        .with_filter(Filter::field(Field::custom("variants.holo")).not_null());
+    assert_eq!(
+        query.to_string(),
+        "name=pika&name=not:raichu&hp=gte:60|120&retreat=lt:2\
+         &set.name=eq:Sword%20%26%20Shield&evolveFrom=null:&variants.holo=notnull:"
+    )
+}
+
+#[test]
+fn raw_filters_are_encoded() {
+    let query = Query::new().with_filtering(vec!["name=eq:Sword & Shield|Scarlet", "hp=100"]);
+    assert_eq!(
+        query.to_string(),
+        "name=eq:Sword%20%26%20Shield|Scarlet&hp=100"
+    )
+}
+
+#[test]
+fn get_sets_with_typed_filter() {
+    let server = MockServer::empty();
+    server.mount(
+        "/en/sets?cardCount.total=gt:200",
+        MockResponse::json(
+            r#"[{"id":"swsh3","name":"Darkness Ablaze","cardCount":{"total":201,"official":189}}]"#,
+        ),
+    );
+    let tcgdex = server.tcgdex();
+    let filter = Query::new().with_filter(Filter::field(Field::CARD_COUNT_TOTAL).gt(200));
+    let sets: Vec<SetBrief> = tcgdex
+        .sets()
+        .fetch(Some(&filter))
+        .expect("The API should returns a sets list");
+    assert_eq!(sets.len(), 1);
+    assert_eq!(sets[0].id, "swsh3")
+}
+
+#[test]
+fn query_parameters_accumulate() {
+    let query = Query::new()
+        .with_filtering(vec!["name=furret"])
+        .with_filter(Filter::field(Field::HP).gt(50))
+        .with_sorting("hp", &query::Order::DESC)
+        .with_sorting("name", &query::Order::ASC)
+        .with_pagination(2, 10);
+    assert_eq!(query.validate(), Ok(()));
+    assert_eq!(
+        query.to_string(),
+        "name=furret&hp=gt:50&pagination:page=2&pagination:itemsPerPage=10\
+         &sort:field=hp&sort:order=DESC&sort:field=name&sort:order=ASC"
+    )
+}
+
+#[test]
+fn conflicting_queries_are_reported() {
+    let with_filter = Query::new()
+        .with_filtering(vec!["name=furret"])
+        .with_id("swsh3-136");
+    assert_eq!(with_filter.validate(), Err(QueryError::IdWithFiltering));
+    let with_sorting = Query::new()
+        .with_id("swsh3-136")
+        .with_sorting("name", &query::Order::ASC);
+    assert_eq!(with_sorting.validate(), Err(QueryError::IdWithSorting));
+    let with_pagination = Query::new().with_pagination(1, 10).with_id("swsh3-136");
+    assert_eq!(
+        with_pagination.validate(),
+        Err(QueryError::IdWithPagination)
+    );
+    let two_ids = Query::new().with_id("swsh3-136").with_id("swsh3-137");
+    assert_eq!(
+        two_ids.validate(),
+        Err(QueryError::ConflictingIds(
+            "swsh3-136".to_string(),
+            "swsh3-137".to_string()
+        ))
+    );
+    let two_orders = Query::new()
+        .with_sorting("name", &query::Order::ASC)
+        .with_sorting("name", &query::Order::DESC);
+    assert_eq!(
+        two_orders.validate(),
+        Err(QueryError::ConflictingSortOrders("name".to_string()))
+    );
+}
+
+#[test]
+fn invalid_query_is_not_sent() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let query = Query::new()
+        .with_id("swsh3-136")
+        .with_filtering(vec!["name=furret"]);
+    let error = tcgdex
+        .cards()
+        .fetch::<Card>(Some(&query))
+        .expect_err("The query is invalid");
+    assert!(error.is_invalid_query());
+    let mut cards = tcgdex.cards().paginate(Some(&query), 10);
+    assert!(cards
+        .next()
+        .expect("An error should be returned")
+        .expect_err("The query is invalid")
+        .is_invalid_query());
+    assert!(cards.next().is_none());
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn fresh_responses_come_from_cache() {
+    let server = MockServer::start();
+    let tcgdex = TcgdexBuilder::new()
+        .with_base_url(&server.base_url())
+        .with_cache(MemoryCache::new(10), Duration::from_secs(3600))
+        .build()
+        .expect("The client should be built");
+    for _ in 0..3 {
+        let sets: Vec<SetBrief> = tcgdex
+            .sets()
+            .fetch(None)
+            .expect("The API should returns a sets list");
+        assert_eq!(sets.len(), 6);
+    }
+    let types = tcgdex
+        .types()
+        .fetch()
+        .expect("The API should returns types");
+    assert_eq!(types.len(), 11);
+    let types = tcgdex
+        .types()
+        .fetch()
+        .expect("The API should returns types");
+    assert_eq!(types.len(), 11);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn cloned_builders_share_cache_and_rate_limit() {
+    let server = MockServer::start();
+    let builder = TcgdexBuilder::new()
+        .with_base_url(&server.base_url())
+        .with_cache(MemoryCache::new(10), Duration::from_secs(3600))
+        .with_rate_limit(RateLimiter::new(1, Duration::from_millis(300)));
+    let first = builder.clone().build().expect("The client should be built");
+    let second = builder.build().expect("The client should be built");
+    let start = Instant::now();
+    first.types().fetch().expect("The API should returns types");
+    second
+        .types()
+        .fetch()
+        .expect("The cache should returns types");
+    assert_eq!(server.requests().len(), 1);
+    second
+        .series()
+        .fetch::<Vec<SerieBrief>>(None)
+        .expect("The API should returns series");
+    // the second request waits for the limit of the first client.
+    assert!(start.elapsed() >= Duration::from_millis(250));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn stale_responses_are_revalidated() {
+    let server = MockServer::start();
+    let body = r#"[{"id":"swsh","name":"Sword & Shield"}]"#;
+    server.mount(
+        "/en/series",
+        MockResponse::json(body)
+            .with_header("ETag", "\"v1\"")
+            .with_header("Last-Modified", "Wed, 14 Aug 2024 10:00:00 GMT"),
+    );
+    let tcgdex = TcgdexBuilder::new()
+        .with_base_url(&server.base_url())
+        .with_cache(MemoryCache::new(10), Duration::ZERO)
+        .build()
+        .expect("The client should be built");
+    let series: Vec<SerieBrief> = tcgdex
+        .series()
+        .fetch(None)
+        .expect("The API should returns series");
+    assert_eq!(series.len(), 1);
+
+    server.mount("/en/series", MockResponse::json("").with_status(304));
+    let series: Vec<SerieBrief> = tcgdex
+        .series()
+        .fetch(None)
+        .expect("The cached series should be used");
+    assert_eq!(series[0].id, "swsh");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].header("If-None-Match"), None);
+    assert_eq!(requests[1].header("If-None-Match"), Some("\"v1\""));
+    assert_eq!(
+        requests[1].header("If-Modified-Since"),
+        Some("Wed, 14 Aug 2024 10:00:00 GMT")
+    );
+}
+
+#[test]
+fn errors_are_not_cached() {
+    let server = MockServer::empty();
+    let tcgdex = TcgdexBuilder::new()
+        .with_base_url(&server.base_url())
+        .with_cache(MemoryCache::new(10), Duration::from_secs(3600))
+        .build()
+        .expect("The client should be built");
+    for _ in 0..2 {
+        let error = tcgdex
+            .cards()
+            .fetch::<Card>(Some(&Query::new().with_id("test-1")))
+            .expect_err("Nothing is mounted");
+        assert!(error.is_not_found());
+    }
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn memory_cache_evicts_least_recently_used() {
+    let cache = MemoryCache::new(2);
+    let key = |url: &str| CacheKey {
+        lang: Lang::EN,
+        url: url.to_string(),
+    };
+    let response = CachedResponse {
+        body: "[]".to_string(),
+        etag: None,
+        last_modified: None,
+        stored_at: SystemTime::now(),
+    };
+    cache.put(&key("a"), response.clone());
+    cache.put(&key("b"), response.clone());
+    assert!(cache.get(&key("a")).is_some());
+    cache.put(&key("c"), response);
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get(&key("a")).is_some());
+    assert!(cache.get(&key("b")).is_none());
+    assert!(cache.get(&key("c")).is_some());
+}
+
+#[test]
+fn disk_cache_survives_clients() {
+    let dir = std::env::temp_dir().join(format!("tcgdex-cache-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let server = MockServer::start();
+    for lang in [Lang::EN, Lang::EN, Lang::FR] {
+        let tcgdex = TcgdexBuilder::new()
+            .with_base_url(&server.base_url())
+            .with_lang(lang)
+            .with_cache(
+                DiskCache::new(&dir).expect("The directory should be created"),
+                Duration::from_secs(3600),
+            )
+            .build()
+            .expect("The client should be built");
+        let types = tcgdex
+            .types()
+            .fetch()
+            .expect("The API should returns types");
+        assert_eq!(types.len(), 11);
+    }
+    // one request by langage.
+    assert_eq!(server.requests().len(), 2);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn disk_cache_is_written_concurrently() {
+    let dir = std::env::temp_dir().join(format!("tcgdex-cache-writers-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let cache = DiskCache::new(&dir).expect("The directory should be created");
+    let key = CacheKey {
+        lang: Lang::EN,
+        url: "https://api.tcgdex.net/v2/en/cards".to_string(),
+    };
+    let bodies: Vec<String> = (0..8)
+        .map(|writer| format!("[{}]", writer.to_string().repeat(100_000)))
+        .collect();
+    std::thread::scope(|scope| {
+        for body in &bodies {
+            let (cache, key) = (&cache, &key);
+            scope.spawn(move || {
+                for _ in 0..10 {
+                    let response = CachedResponse {
+                        body: body.clone(),
+                        etag: None,
+                        last_modified: None,
+                        stored_at: SystemTime::now(),
+                    };
+                    cache.put(key, response);
+                }
+            });
+        }
+    });
+    let stored = cache
+        .get(&key)
+        .expect("A complete response should be stored");
+    assert!(bodies.contains(&stored.body));
+    // no temporary file is left.
+    let files = std::fs::read_dir(&dir).expect("The directory should be read");
+    assert_eq!(files.count(), 1);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn failed_requests_are_retried() {
+    let server = MockServer::empty();
+    server.mount_sequence(
+        "/en/types",
+        vec![
+            MockResponse::tcgdex_error(503, "Service unavailable", "/en/types"),
+            MockResponse::json("")
+                .with_status(429)
+                .with_header("Retry-After", "0"),
+            MockResponse::json(r#"["Fire","Water"]"#),
+        ],
+    );
+    let tcgdex = TcgdexBuilder::new()
+        .with_base_url(&server.base_url())
+        .with_retry(
+            RetryPolicy::new().with_backoff(Duration::from_millis(1), Duration::from_millis(10)),
+        )
+        .build()
+        .expect("The client should be built");
+    let types = tcgdex
+        .types()
+        .fetch()
+        .expect("The third attempt should succeed");
+    assert_eq!(types, vec![PokemonType::Fire, PokemonType::Water]);
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn retries_stop_after_max_attempts() {
+    let server = MockServer::empty();
+    server.mount(
+        "/en/types",
+        MockResponse::tcgdex_error(503, "Service unavailable", "/en/types"),
+    );
+    let tcgdex = TcgdexBuilder::new()
+        .with_base_url(&server.base_url())
+        .with_retry(
+            RetryPolicy::new()
+                .with_max_attempts(2)
+                .with_backoff(Duration::from_millis(1), Duration::from_millis(10)),
+        )
+        .build()
+        .expect("The client should be built");
+    assert!(tcgdex.types().fetch().is_err());
+    assert_eq!(server.requests().len(), 2);
+
+    // not found is not a retry status.
+    let error = tcgdex
+        .cards()
+        .fetch::<Card>(Some(&Query::new().with_id("test-1")))
+        .expect_err("Nothing is mounted");
+    assert!(error.is_not_found());
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn reset_connections_are_retried() {
+    use std::io::{BufRead, BufReader, Write};
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("A port should be bound");
+    let url = format!("http://{}/", listener.local_addr().expect("The address"));
+    let server = std::thread::spawn(move || {
+        let json = r#"["Fire"]"#;
+        let responses = [
+            // closed without reply.
+            String::new(),
+            // closed in the middle of the body.
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n[",
+                json.len()
+            ),
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{json}",
+                json.len()
+            ),
+        ];
+        for response in responses {
+            let (mut stream, _) = listener.accept().expect("A connection should come");
+            let mut reader = BufReader::new(stream.try_clone().expect("The stream"));
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+                line.clear();
+            }
+            stream
+                .write_all(response.as_bytes())
+                .expect("The response should be written");
+        }
+    });
+    let tcgdex = TcgdexBuilder::new()
+        .with_base_url(&url)
+        .with_retry(
+            RetryPolicy::new().with_backoff(Duration::from_millis(1), Duration::from_millis(10)),
+        )
+        .build()
+        .expect("The client should be built");
+    let types = tcgdex
+        .types()
+        .fetch()
+        .expect("The third attempt should succeed");
+    assert_eq!(types, vec![PokemonType::Fire]);
+    server
+        .join()
+        .expect("The server should answer 3 connections");
+}
+
+#[test]
+fn long_retry_after_stops_retries() {
+    let server = MockServer::empty();
+    server.mount_sequence(
+        "/en/types",
+        vec![
+            MockResponse::json("")
+                .with_status(429)
+                .with_header("Retry-After", "3600"),
+            MockResponse::json(r#"["Fire"]"#),
+        ],
+    );
+    let tcgdex = TcgdexBuilder::new()
+        .with_base_url(&server.base_url())
+        .with_retry(
+            RetryPolicy::new().with_backoff(Duration::from_millis(1), Duration::from_millis(10)),
+        )
+        .build()
+        .expect("The client should be built");
+    let error = tcgdex
+        .types()
+        .fetch()
+        .expect_err("The request should not be retried");
+    assert_eq!(
+        error,
+        errors::ApiError::RateLimited {
+            url: format!("{}en/types", server.base_url()),
+            retry_after: Some(Duration::from_secs(3600)),
+        }
+    );
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn malformed_retry_after_dates_are_ignored() {
+    let dates = [
+        "Wed, 00 Jan 0000 00:00:00 GMT",
+        "Wed, 01 Jan 0000 00:00:00 GMT",
+        "Wed, 32 Oct 2015 07:28:00 GMT",
+        "Wed, 21 Oct 2015 24:28:00 GMT",
+        "Wed, 21 Oct 2015 07:60:00 GMT",
+        "Wed, 21 Oct 2015 07:28:61 GMT",
+        "Wed, 21 Oct 2015 07:28 GMT",
+        "Wed, 21 Foo 2015 07:28:00 GMT",
+    ];
+    let server = MockServer::empty();
+    for (index, date) in dates.iter().enumerate() {
+        server.mount(
+            &format!("/en/cards/date-{index}"),
+            MockResponse::json("")
+                .with_status(429)
+                .with_header("Retry-After", date),
+        );
+    }
+    let tcgdex = server.tcgdex();
+    for (index, date) in dates.iter().enumerate() {
+        let filter = Query::new().with_id(&format!("date-{index}"));
+        let error = tcgdex
+            .cards()
+            .fetch::<Card>(Some(&filter))
+            .expect_err("The API is rate limited");
+        assert_eq!(
+            error,
+            errors::ApiError::RateLimited {
+                url: format!("{}en/cards/date-{index}", server.base_url()),
+                retry_after: None,
+            },
+            "{date} should be ignored"
+        );
+    }
+}
+
+#[test]
+fn rate_limiter_spaces_requests() {
+    let server = MockServer::start();
+    let tcgdex = TcgdexBuilder::new()
+        .with_base_url(&server.base_url())
+        .with_rate_limit(RateLimiter::new(2, Duration::from_millis(400)))
+        .build()
+        .expect("The client should be built");
+    let start = Instant::now();
+    for _ in 0..4 {
+        tcgdex
+            .types()
+            .fetch()
+            .expect("The API should returns types");
+    }
+    // 2 requests at once, then one every 200 ms.
+    assert!(start.elapsed() >= Duration::from_millis(350));
+    assert_eq!(server.requests().len(), 4);
+}
+
+#[test]
+fn fetch_card_by_set_and_local_id() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let card = tcgdex
+        .sets()
+        .fetch_card("swsh3", "136/189")
+        .expect("The API should returns a card");
+    assert_eq!(card.id, "swsh3-136");
+    assert_eq!(server.requests().len(), 1);
+
+    // set name and zero-padded local id.
+    server.mount(
+        "/en/sets?name=Darkness%20Ablaze",
+        MockResponse::json(
+            r#"[{"id":"swsh3","name":"Darkness Ablaze","cardCount":{"total":201,"official":189}}]"#,
+        ),
+    );
+    let card = tcgdex
+        .sets()
+        .fetch_card("Darkness Ablaze", "0136")
+        .expect("The set should be found by name");
+    assert_eq!(card.id, "swsh3-136");
+    assert_eq!(
+        server
+            .requests()
+            .last()
+            .map(|request| request.target.clone()),
+        Some("/en/sets/swsh3/136".to_string())
+    );
+
+    // names are matched in any case, ignoring partial matches.
+    server.mount(
+        "/en/sets?name=darkness%20ablaze",
+        MockResponse::json(
+            r#"[{"id":"tk-da","name":"Darkness Ablaze Trainer Kit","cardCount":{"total":30,"official":30}},
+                {"id":"swsh3","name":"Darkness Ablaze","cardCount":{"total":201,"official":189}}]"#,
+        ),
+    );
+    let card = tcgdex
+        .sets()
+        .fetch_card("darkness ablaze", "136")
+        .expect("The set should be found by name in lower case");
+    assert_eq!(card.id, "swsh3-136");
+
+    // a partial name is not guessed.
+    server.mount(
+        "/en/sets?name=Darkness",
+        MockResponse::json(
+            r#"[{"id":"swsh3","name":"Darkness Ablaze","cardCount":{"total":201,"official":189}}]"#,
+        ),
+    );
+    let error = tcgdex
+        .sets()
+        .fetch_card("Darkness", "136")
+        .expect_err("The set should not be found by a partial name");
+    assert!(error.is_not_found());
+
+    // secret rare local id.
+    let mut gardevoir = card;
+    gardevoir.id = "swsh11-TG05".to_string();
+    gardevoir.local_id = "TG05".to_string();
+    let set = Set {
+        id: "swsh11".to_string(),
+        name: "Lost Origin".to_string(),
+        cards: vec![CardBrief {
+            id: gardevoir.id.clone(),
+            local_id: gardevoir.local_id.clone(),
+            name: "Gardevoir".to_string(),
+            ..CardBrief::default()
+        }],
+        ..Set::default()
+    };
+    server.mount(
+        "/en/sets/swsh11",
+        MockResponse::json(&serde_json::to_string(&set).unwrap()),
+    );
+    server.mount(
+        "/en/sets/swsh11/TG05",
+        MockResponse::json(&serde_json::to_string(&gardevoir).unwrap()),
+    );
+    let card = tcgdex
+        .sets()
+        .fetch_card("swsh11", "tg5")
+        .expect("The local id should be found without its leading zero");
+    assert_eq!(card.local_id, "TG05");
+
+    let error = tcgdex
+        .sets()
+        .fetch_card("swsh11", "TG99")
+        .expect_err("The card does not exist");
+    assert!(error.is_not_found());
+}
+
+#[test]
+fn fetch_many_cards() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let ids = ["swsh3-136", "test-1", "swsh3-136", "A1-132"];
+    let cards = tcgdex.cards().fetch_many(&ids);
+    let fetched: Vec<&str> = cards.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(fetched, vec!["swsh3-136", "test-1", "A1-132"]);
+    assert_eq!(
+        cards[0].1.as_ref().expect("The card should exist").name,
+        "Furret"
+    );
+    assert!(cards[1]
+        .1
+        .as_ref()
+        .expect_err("The card does not exist")
+        .is_not_found());
+    assert_eq!(
+        cards[2].1.as_ref().expect("The card should exist").id,
+        "A1-132"
+    );
+    // duplicates are requested once.
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn fetch_many_sets() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let ids = vec!["swsh3".to_string(), "test".to_string()];
+    let sets = tcgdex.sets().fetch_many(&ids);
+    assert_eq!(sets.len(), 2);
+    assert_eq!(
+        sets[0].1.as_ref().expect("The set should exist").name,
+        "Darkness Ablaze"
+    );
+    assert!(sets[1].1.is_err());
+    assert!(tcgdex.sets().fetch_many(&Vec::<String>::new()).is_empty());
+}
+
+#[test]
+fn http_errors_have_their_own_variant() {
+    let server = MockServer::empty();
+    server.mount(
+        "/en/types",
+        MockResponse::json("")
+            .with_status(429)
+            .with_header("Retry-After", "30"),
+    );
+    server.mount(
+        "/en/rarities",
+        MockResponse::tcgdex_error(500, "Internal error", "/en/rarities"),
+    );
+    server.mount(
+        "/en/hp",
+        MockResponse::tcgdex_error(400, "Bad request", "/en/hp"),
+    );
+    let tcgdex = server.tcgdex();
+
+    let error = tcgdex.types().fetch().expect_err("The API is rate limited");
+    assert!(error.is_rate_limited());
+    assert_eq!(
+        error,
+        errors::ApiError::RateLimited {
+            url: format!("{}en/types", server.base_url()),
+            retry_after: Some(Duration::from_secs(30)),
+        }
+    );
+    let error = tcgdex.rarities().fetch().expect_err("The API fails");
+    assert!(error.is_server_error());
+    assert_eq!(
+        error
+            .get_tcgdex_error()
+            .expect("The error has details")
+            .title,
+        "Internal error"
+    );
+    let error = tcgdex.hps().fetch().expect_err("The request is invalid");
+    assert!(error.is_tcgdexapi());
+    assert_eq!(format!("{error}"), "Tcgdex error : Bad request");
+    let error = tcgdex
+        .illustrators()
+        .fetch()
+        .expect_err("Nothing is mounted");
+    assert!(error.is_not_found());
+}
+
+#[test]
+fn deserialization_errors_locate_the_value() {
+    let server = MockServer::empty();
+    server.mount(
+        "/en/cards/swsh3-136",
+        MockResponse::json(
+            r#"{"id":"swsh3-136","localId":"136","name":"Furret","hp":110,"attacks":[{"name":"Tail Smash","cost":"Colorless"}]}"#,
+        ),
+    );
+    let tcgdex = server.tcgdex();
+    let error = tcgdex
+        .cards()
+        .fetch::<Card>(Some(&Query::new().with_id("swsh3-136")))
+        .expect_err("The attack cost is not a list");
+    let errors::ApiError::Deserialization {
+        url, path, snippet, ..
+    } = &error
+    else {
+        panic!("A deserialization error is expected, got {error:?}");
+    };
+    assert_eq!(url, &format!("{}en/cards/swsh3-136", server.base_url()));
+    assert_eq!(path, "attacks[0].cost");
+    assert!(snippet.contains("\"cost\":\"Colorless\""));
+    assert!(error.is_deserialization());
+}
+
+#[test]
+fn timeouts_are_reported() {
+    // accept connections but never answer.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("A port should be bound");
+    let url = format!("http://{}/", listener.local_addr().expect("The address"));
+    let tcgdex = TcgdexBuilder::new()
+        .with_base_url(&url)
+        .with_timeout(Duration::from_millis(200))
+        .build()
+        .expect("The client should be built");
+    let error = tcgdex
+        .types()
+        .fetch()
+        .expect_err("The server never answers");
+    assert!(error.is_timeout());
+    assert_eq!(error.url(), Some(format!("{url}en/types").as_str()));
+    drop(listener);
+}
+
+#[test]
+fn lenient_decode_coerces_values() {
+    let server = MockServer::empty();
+    server.mount(
+        "/en/cards/swsh3-136",
+        MockResponse::json(
+            r#"{"id":"swsh3-136","localId":"136","name":"Furret","hp":"110 HP",
+                "types":["Colorless",5],"set":{"id":"swsh3","name":"Darkness Ablaze"},
+                "newField":{"since":"2030"}}"#,
+        ),
+    );
+    let tcgdex = server.tcgdex();
+    assert!(tcgdex
+        .cards()
+        .fetch::<Card>(Some(&Query::new().with_id("swsh3-136")))
+        .expect_err("The strict decode fails")
+        .is_deserialization());
+
+    let card = tcgdex
+        .cards()
+        .fetch_lenient("swsh3-136")
+        .expect("The lenient decode succeeds");
+    assert_eq!(card.value.name, "Furret");
+    assert_eq!(card.value.hp, 0);
+    assert_eq!(card.value.types, vec![PokemonType::Colorless]);
+    assert_eq!(card.value.set.card_count.total, 0);
+    assert_eq!(card.value.extra["newField"]["since"], "2030");
+
+    let warning = |path: &str| {
+        card.warnings
+            .iter()
+            .find(|warning| warning.path == path)
+            .map(|warning| warning.coercion)
+    };
+    assert_eq!(warning("hp"), Some(Coercion::Defaulted));
+    assert_eq!(warning("types[1]"), Some(Coercion::Dropped));
+    assert_eq!(warning("set.cardCount"), Some(Coercion::Defaulted));
+    assert_eq!(warning("newField"), Some(Coercion::Unknown));
+    assert_eq!(warning("name"), None);
+}
+
+#[test]
+fn lenient_decode_keeps_unknown_fields() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let set = tcgdex
+        .sets()
+        .fetch_lenient("swsh3")
+        .expect("The set should exist");
+    assert_eq!(set.value.name, "Darkness Ablaze");
+    assert!(set
+        .warnings
+        .iter()
+        .all(|warning| warning.coercion == Coercion::Unknown));
+    for warning in &set.warnings {
+        assert!(set.value.extra.contains_key(&warning.path));
+    }
+
+    server.mount("/en/series/swsh", MockResponse::json("[1, 2]"));
+    assert!(tcgdex
+        .series()
+        .fetch_lenient("swsh")
+        .expect_err("A list is not a serie")
+        .is_deserialization());
+    assert!(tcgdex
+        .series()
+        .fetch_lenient("test")
+        .expect_err("Nothing is mounted")
+        .is_not_found());
+}
+
+#[test]
+fn models_round_trip_to_json() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let card: Card = tcgdex
+        .cards()
+        .fetch(Some(&Query::new().with_id("swsh3-136")))
+        .expect("The API should returns a card");
+    let json = serde_json::to_value(&card).expect("The card should be serialized");
+    assert_eq!(json["localId"], "136");
+    assert_eq!(json["category"], "Pokemon");
+    assert_eq!(json["types"], serde_json::json!(["Colorless"]));
+    assert_eq!(json["stage"], "Stage1");
+    assert_eq!(json["attacks"][1]["damage"], 90);
+    assert_eq!(
+        json["weaknesses"][0],
+        serde_json::json!({"type": "Fighting", "value": "×2"})
+    );
+    assert_eq!(json["set"]["cardCount"]["total"], 201);
+    assert_eq!(json["variants"]["wPromo"], false);
+    assert_eq!(json["variants_detailed"][1]["type"], "reverse");
+    let decoded: Card = serde_json::from_value(json).expect("The card should be decoded");
+    assert_eq!(decoded, card);
+
+    let set: Set = tcgdex
+        .sets()
+        .fetch(Some(&Query::new().with_id("swsh3")))
+        .expect("The API should returns a set");
+    let decoded: Set =
+        serde_json::from_str(&serde_json::to_string(&set).expect("The set should be serialized"))
+            .expect("The set should be decoded");
+    assert_eq!(decoded, set);
+
+    let damages = r#"[10, "30+", "20×", "50-"]"#;
+    let decoded: Vec<Damage> = serde_json::from_str(damages).expect("Damages should be decoded");
+    let json = serde_json::to_string(&decoded).expect("Damages should be serialized");
+    assert_eq!(json, r#"[10,"30+","20×","50-"]"#);
+    let unknown = Rarity::from("Mythic Secret".to_string());
+    assert_eq!(
+        serde_json::to_string(&unknown).expect("The rarity should be serialized"),
+        r#""Mythic Secret""#
+    );
+}
+
+#[test]
+fn models_serialize_like_the_api() {
+    let sources = [
+        include_str!("../src/test_support/fixtures/en/cards/swsh3-136.json"),
+        include_str!("../src/test_support/fixtures/en/cards/A1-132.json"),
+    ];
+    for source in sources {
+        let json: serde_json::Value = serde_json::from_str(source).expect("The fixture is JSON");
+        let card: Card = serde_json::from_value(json.clone()).expect("The card should be decoded");
+        let serialized = serde_json::to_value(&card).expect("The card should be serialized");
+        assert_eq!(
+            serialized, json,
+            "{} should be serialized as received",
+            card.id
+        );
+    }
+
+    let source = include_str!("../src/test_support/fixtures/en/series/swsh.json");
+    let json: serde_json::Value = serde_json::from_str(source).expect("The fixture is JSON");
+    let serie: Serie = serde_json::from_value(json.clone()).expect("The serie should be decoded");
+    let serialized = serde_json::to_value(&serie).expect("The serie should be serialized");
+    assert_eq!(serialized, json);
+}
+
+#[test]
+fn models_round_trip_to_cbor() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let card: Card = tcgdex
+        .cards()
+        .fetch(Some(&Query::new().with_id("swsh3-136")))
+        .expect("The API should returns a card");
+    let bytes = cbor::to_vec(&card).expect("The card should be encoded");
+    assert!(bytes.len() < serde_json::to_vec(&card).expect("JSON").len());
+    assert_eq!(
+        cbor::from_slice::<Card>(&bytes).expect("The card should be decoded"),
+        card
+    );
+
+    let series: Serie = tcgdex
+        .series()
+        .fetch(Some(&Query::new().with_id("swsh")))
+        .expect("The API should returns a serie");
+    let mut file = Vec::new();
+    cbor::to_writer(&series, &mut file).expect("The serie should be written");
+    let decoded: Serie = cbor::from_reader(file.as_slice()).expect("The serie should be read");
+    assert_eq!(decoded, series);
+    assert!(cbor::from_slice::<Card>(&[0xff]).is_err());
+}
+
+#[test]
+fn image_urls() {
+    let server = MockServer::start();
+    let tcgdex = server.tcgdex();
+    let card: Card = tcgdex
+        .cards()
+        .fetch(Some(&Query::new().with_id("swsh3-136")))
+        .expect("The API should returns a card");
+    let image = card.image_asset().expect("The card has an image");
+    assert_eq!(
+        image.url(Quality::High, Extension::Webp),
+        "https://assets.tcgdex.net/en/swsh/swsh3/136/high.webp"
+    );
+    assert_eq!(
+        image.url(Quality::Low, Extension::Png),
+        "https://assets.tcgdex.net/en/swsh/swsh3/136/low.png"
+    );
+    assert_eq!(
+        card.set
+            .symbol_asset()
+            .map(|symbol| symbol.url(Extension::Jpg)),
+        Some("https://assets.tcgdex.net/univ/swsh/swsh3/symbol.jpg".to_string())
+    );
+
+    let set: Set = tcgdex
+        .sets()
+        .fetch(Some(&Query::new().with_id("swsh3")))
+        .expect("The API should returns a set");
+    assert_eq!(
+        set.logo_asset().map(|logo| logo.url(Extension::Png)),
+        Some("https://assets.tcgdex.net/en/swsh/swsh3/logo.png".to_string())
+    );
+
+    let brief = CardBrief {
+        id: "test-1".to_string(),
+        image: "  ".to_string(),
+        ..CardBrief::default()
+    };
+    assert_eq!(brief.image_asset(), None);
+    assert_eq!(Serie::default().logo_asset(), None);
+}
+
+#[test]
+fn image_mirror_resumes_and_skips_present_files() {
+    let dir = std::env::temp_dir().join(format!("tcgdex-mirror-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let server = MockServer::start();
+    let base_url = server.base_url();
+    server.mount("/img/logo.webp", MockResponse::json("logo"));
+    server.mount("/img/1/high.webp", MockResponse::json("first card"));
+    // same content as the logo: stored once.
+    server.mount("/img/2/high.webp", MockResponse::json("logo"));
+    let tcgdex = server.tcgdex();
+    let set = Set {
+        id: "test".to_string(),
+        logo: format!("{base_url}img/logo"),
+        cards: ["1", "2", "3"]
+            .into_iter()
+            .map(|id| CardBrief {
+                id: format!("test-{id}"),
+                image: format!("{base_url}img/{id}"),
+                ..CardBrief::default()
+            })
+            .collect(),
+        ..Set::default()
+    };
+
+    let mirror = ImageMirror::new(&tcgdex, &dir).expect("The directory should be created");
+    let report = mirror.download_set(&set);
+    assert_eq!(report.downloaded.len(), 3);
+    assert_eq!(report.missing, vec!["test symbol".to_string()]);
+    assert_eq!(report.failed.len(), 1);
+    assert!(!report.is_complete());
+    let logo = mirror
+        .path(&format!("{base_url}img/logo.webp"))
+        .expect("The logo should be in the mirror");
+    assert_eq!(std::fs::read_to_string(&logo).unwrap(), "logo");
+    assert_eq!(
+        mirror.path(&format!("{base_url}img/2/high.webp")),
+        Some(logo)
+    );
+    let requests = server.requests().len();
+
+    // a new mirror on the same directory resumes from its index.
+    let mirror = ImageMirror::new(&tcgdex, &dir).expect("The directory should be read");
+    let report = mirror.download_set(&set);
+    assert!(report.downloaded.is_empty());
+    assert_eq!(report.skipped.len(), 3);
+    assert_eq!(server.requests().len(), requests + 1);
+
+    // a file without its expected size is downloaded again.
+    let card = mirror
+        .path(&format!("{base_url}img/1/high.webp"))
+        .expect("The card should be in the mirror");
+    std::fs::write(&card, "first").unwrap();
+    let report = mirror.download_set(&set);
+    assert_eq!(
+        report.downloaded,
+        vec![format!("{base_url}img/1/high.webp")]
+    );
+    assert_eq!(std::fs::read_to_string(&card).unwrap(), "first card");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn image_mirror_writes_same_images_concurrently() {
+    let dir = std::env::temp_dir().join(format!("tcgdex-mirror-same-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let server = MockServer::start();
+    let base_url = server.base_url();
+    let set = Set {
+        id: "test".to_string(),
+        cards: (0..32)
+            .map(|id| {
+                server.mount(
+                    &format!("/img/{id}/high.webp"),
+                    MockResponse::json("same image"),
+                );
+                CardBrief {
+                    id: format!("test-{id}"),
+                    image: format!("{base_url}img/{id}"),
+                    ..CardBrief::default()
+                }
+            })
+            .collect(),
+        ..Set::default()
+    };
+    let tcgdex = server.tcgdex();
+    let mirror = ImageMirror::new(&tcgdex, &dir).expect("The directory should be created");
+    let report = mirror.download_set(&set);
+    assert!(report.is_complete(), "{:?}", report.failed);
+    assert_eq!(report.downloaded.len(), 32);
+    // one image, without temporary files left.
+    let objects: Vec<_> = std::fs::read_dir(dir.join("objects"))
+        .unwrap()
+        .flat_map(|entry| std::fs::read_dir(entry.unwrap().path()).unwrap())
+        .collect();
+    assert_eq!(objects.len(), 1);
+    let _ = std::fs::remove_dir_all(&dir);
+}